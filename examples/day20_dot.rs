@@ -0,0 +1,28 @@
+//! Prints a day 20 module network as a Graphviz graph:
+//!
+//! ```text
+//! cargo run --example day20_dot < inputs/day20.txt | dot -Tsvg > network.svg
+//! ```
+
+use std::io::Read;
+use std::process::ExitCode;
+
+use aoc23::day20::Network;
+
+fn main() -> ExitCode {
+    let mut input = String::new();
+    if let Err(err) = std::io::stdin().read_to_string(&mut input) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+    match Network::parse(&input) {
+        Ok(network) => {
+            print!("{}", network.to_dot());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
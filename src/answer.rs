@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// A puzzle answer. Most are unsigned integers, but some puzzles want a
+/// signed number, something wider than `u64`, or text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Wide(u128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(answer) => write!(f, "{answer}"),
+            Answer::Signed(answer) => write!(f, "{answer}"),
+            Answer::Wide(answer) => write!(f, "{answer}"),
+            Answer::Text(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(answer: u64) -> Self {
+        Answer::Unsigned(answer)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(answer: i64) -> Self {
+        Answer::Signed(answer)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(answer: u128) -> Self {
+        Answer::Wide(answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::Text(answer.to_owned())
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{Answer, Error, Part, Result};
+
+/// Expected answers, one `[dayNN]` table per day with `part1`/`part2` keys:
+///
+/// ```toml
+/// [day01]
+/// part1 = 142
+/// part2 = 281
+/// ```
+#[derive(Deserialize, Debug)]
+pub struct Answers(BTreeMap<String, ExpectedDay>);
+
+#[derive(Deserialize, Debug)]
+struct ExpectedDay {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+/// Outcome of comparing an answer against the answers file.
+pub enum Verdict {
+    Unchecked,
+    Correct,
+    Wrong(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Unchecked => write!(f, "?"),
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Wrong(expected) => write!(f, "expected {expected}"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(|err| {
+            Error::Io(format!("Error reading answers ({}): {err}", path.display()))
+        })?;
+        toml::from_str(&text)
+            .map_err(|err| Error::Io(format!("Error parsing answers ({}): {err}", path.display())))
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        let expected = self
+            .0
+            .get(&format!("day{day:02}"))
+            .and_then(|expected| match part {
+                Part::One => expected.part1.as_ref(),
+                Part::Two => expected.part2.as_ref(),
+            });
+        let expected = match expected {
+            None => return Verdict::Unchecked,
+            Some(toml::Value::String(string)) => string.clone(),
+            Some(value) => value.to_string(),
+        };
+        if expected == answer.to_string() {
+            Verdict::Correct
+        } else {
+            Verdict::Wrong(expected)
+        }
+    }
+}
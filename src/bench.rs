@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{Answer, Day, Params, Part, Result};
+
+/// Min, median and max of a set of timings, in nanoseconds.
+#[derive(Serialize, Debug)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub max_ns: u128,
+}
+
+impl Stats {
+    pub fn from_durations(mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+        Self {
+            min_ns: durations.first().map_or(0, Duration::as_nanos),
+            median_ns: durations
+                .get(durations.len() / 2)
+                .map_or(0, Duration::as_nanos),
+            max_ns: durations.last().map_or(0, Duration::as_nanos),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [min, median, max] =
+            [self.min_ns, self.median_ns, self.max_ns].map(|ns| Duration::from_nanos(ns as u64));
+        write!(f, "min {min:?}, median {median:?}, max {max:?}")
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct BenchReport {
+    pub day: u8,
+    pub runs: u32,
+    pub parse: Stats,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub solve: Stats,
+}
+
+/// Solves `parts` of `day` `runs` times (at least once) and summarises the timings.
+pub fn bench(
+    day: &Day,
+    params: &Params,
+    parts: &[Part],
+    input: &str,
+    runs: u32,
+) -> Result<BenchReport> {
+    let timings = (0..runs.max(1))
+        .map(|_| (day.solve)(input, params, parts))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(BenchReport {
+        day: day.number,
+        runs,
+        parse: Stats::from_durations(timings.iter().map(|solved| solved.parse).collect()),
+        parts: timings[0]
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(index, timed)| PartReport {
+                part: timed.part.number(),
+                answer: timed.answer.clone(),
+                solve: Stats::from_durations(
+                    timings
+                        .iter()
+                        .map(|solved| solved.parts[index].solve)
+                        .collect(),
+                ),
+            })
+            .collect(),
+    })
+}
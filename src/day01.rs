@@ -0,0 +1,135 @@
+//! Day 1: Trebuchet?!
+
+use aho_corasick::*;
+
+use crate::{Answer, Error, Result, Solver};
+
+fn calibration_sum(input: &str) -> Result<u64> {
+    let mut numbers = Vec::<u64>::new();
+    for line in input.lines() {
+        let mut num_str = String::new();
+        for char in line.chars() {
+            if char.is_ascii_digit() {
+                num_str.push(char);
+                break;
+            }
+        }
+        for char in line.chars().rev() {
+            if char.is_ascii_digit() {
+                num_str.push(char);
+                break;
+            }
+        }
+        numbers.push(
+            num_str
+                .parse()
+                .map_err(|_| Error::at(input, line, "line contains no digits"))?,
+        );
+    }
+    Ok(numbers.iter().sum())
+}
+
+pub fn parse_digit(digit: &str) -> Result<u8, <u8 as std::str::FromStr>::Err> {
+    match digit {
+        "one" => Ok(1),
+        "two" => Ok(2),
+        "three" => Ok(3),
+        "four" => Ok(4),
+        "five" => Ok(5),
+        "six" => Ok(6),
+        "seven" => Ok(7),
+        "eight" => Ok(8),
+        "nine" => Ok(9),
+        _ => digit.parse::<u8>(),
+    }
+}
+
+fn spelled_calibration_sum(input: &str) -> Result<u64> {
+    let mut total = 0u64;
+    let ac = AhoCorasick::new([
+        "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+        "seven", "eight", "nine",
+    ])
+    .unwrap();
+
+    for line in input.lines() {
+        let mut num = 0u64;
+        let vec: Vec<_> = ac.find_overlapping_iter(line).collect();
+
+        if !vec.is_empty() {
+            num += 10 * parse_digit(&line[vec.first().unwrap().span()]).unwrap_or(0) as u64;
+            num += parse_digit(&line[vec.last().unwrap().span()]).unwrap_or(0) as u64;
+        }
+        total += num;
+    }
+    Ok(total)
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    // Each part reads digits off the lines differently, so there's nothing to share
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+    fn part_one(input: &Self::Parsed<'_>) -> Result<Answer> {
+        calibration_sum(input).map(Answer::from)
+    }
+    fn part_two(input: &Self::Parsed<'_>) -> Result<Answer> {
+        spelled_calibration_sum(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one_example() {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        let result = Day01::solve(input, Part::One);
+        assert_eq!(result, Ok(142u64.into()))
+    }
+    #[test]
+    fn test_part_two_example() {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        let result = Day01::solve(input, Part::Two);
+        assert_eq!(result, Ok(281u64.into()))
+    }
+    #[test]
+    fn test_part_two_one_digit() {
+        let input = "5";
+        let result = Day01::solve(input, Part::Two);
+        assert_eq!(result, Ok(55u64.into()))
+    }
+    #[test]
+    fn test_part_two_oneight() {
+        let input = "oneight";
+        let result = Day01::solve(input, Part::Two);
+        assert_eq!(result, Ok(18u64.into()))
+    }
+    #[test]
+    fn test_part_one_no_digits() {
+        let result = Day01::solve("1abc2\npqrstu", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                message: "line contains no digits".to_owned()
+            })
+        )
+    }
+}
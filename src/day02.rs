@@ -0,0 +1,126 @@
+//! Day 2: Cube Conundrum
+
+use regex::*;
+
+use crate::{Answer, Error, Result, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+
+pub fn parse_rounds<'a>(input: &'a str, line: &'a str) -> Result<Rgb> {
+    let re = Regex::new(r"(?<count>\d+) (?<color>red|green|blue)").unwrap();
+    let mut red = 0;
+    let mut green = 0;
+    let mut blue = 0;
+    for round in line.split(';') {
+        for (_, [count_str, color_str]) in re.captures_iter(round).map(|c| c.extract()) {
+            let count: i32 = count_str
+                .parse()
+                .map_err(|err| Error::at(input, count_str, format!("bad cube count: {err}")))?;
+            match color_str {
+                "red" => red = red.max(count),
+                "green" => green = green.max(count),
+                "blue" => blue = blue.max(count),
+                _ => return Err(Error::at(input, color_str, "unknown cube color")),
+            }
+        }
+    }
+    Ok(Rgb { red, green, blue })
+}
+
+/// A game's number and the most cubes of each color shown in any round.
+pub struct Game {
+    pub number: u64,
+    pub max: Rgb,
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>> {
+    input
+        .lines()
+        .map(|line| {
+            let game_str = line
+                .split(':') // Get "Game NN" and rest
+                .next()
+                .and_then(|game| game.split(' ').next_back()) // Get "Game" and "NN"
+                .unwrap_or(line);
+            let number: u64 = game_str
+                .parse()
+                .map_err(|err| Error::at(input, game_str, format!("bad game number: {err}")))?;
+
+            Ok(Game {
+                number,
+                max: parse_rounds(input, line)?,
+            })
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_games(input)
+    }
+    fn part_one(games: &Self::Parsed<'_>) -> Result<Answer> {
+        let max_red = 12;
+        let max_green = 13;
+        let max_blue = 14;
+
+        let mut sum = 0u64;
+
+        for game in games {
+            let Rgb { red, green, blue } = game.max;
+
+            if red <= max_red && green <= max_green && blue <= max_blue {
+                sum += game.number;
+            } else {
+                //println!("Rejecting  r: {red} g: {green} b: {blue} :'{line}'")
+            }
+        }
+
+        Ok(sum.into())
+    }
+    fn part_two(games: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut sum = 0u64;
+
+        for game in games {
+            let Rgb { red, green, blue } = game.max;
+            sum += (red * green * blue) as u64;
+        }
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let result = Day02::solve(input, Part::One);
+        assert_eq!(result, Ok(8u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let result = Day02::solve(input, Part::Two);
+        assert_eq!(result, Ok(2286u64.into()))
+    }
+}
@@ -0,0 +1,151 @@
+//! Day 3: Gear Ratios
+
+use std::collections::*;
+
+use crate::grid::{self, Grid, Pos};
+use crate::{Answer, Error, Result, Solver};
+
+/// A number in the schematic, and where its first digit sits.
+pub struct PartNumber {
+    pub pos: Pos,
+    pub value: u64,
+}
+
+/// The engine schematic, its numbers, and which number covers each cell.
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<PartNumber>,
+    owners: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, Some)?;
+        let mut owners = Grid::parse(input, |_| Some(None))?;
+        let mut numbers: Vec<PartNumber> = Vec::new();
+        for pos in grid.positions() {
+            let Some(digit) = grid[pos].to_digit(10) else {
+                continue;
+            };
+            let continues = pos.col > 0 && owners[Pos::new(pos.row, pos.col - 1)].is_some();
+            if !continues {
+                numbers.push(PartNumber { pos, value: 0 });
+            }
+            let number = numbers.last_mut().expect("a number was just started");
+            number.value = number
+                .value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit.into()))
+                .ok_or_else(|| {
+                    Error::at(
+                        input,
+                        grid::source(input, number.pos),
+                        "part number too large",
+                    )
+                })?;
+            owners[pos] = Some(numbers.len() - 1);
+        }
+        Ok(Schematic {
+            grid,
+            numbers,
+            owners,
+        })
+    }
+
+    /// Indices into `numbers` of the numbers touching `pos`, diagonals included.
+    pub fn adjacent_numbers(&self, pos: Pos) -> HashSet<usize> {
+        self.grid
+            .adjacent(pos)
+            .filter_map(|other| self.owners[other])
+            .collect()
+    }
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Parsed<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Schematic::parse(input)
+    }
+    fn part_one(schematic: &Self::Parsed<'_>) -> Result<Answer> {
+        let symbols = ['%', '+', '=', '$', '@', '#', '/', '*', '&', '-'];
+
+        let mut to_process: HashSet<usize> = HashSet::new();
+        for pos in schematic.grid.positions() {
+            if symbols.contains(&schematic.grid[pos]) {
+                to_process.extend(schematic.adjacent_numbers(pos));
+            }
+        }
+
+        let sum: u64 = to_process
+            .into_iter()
+            .map(|number| schematic.numbers[number].value)
+            .sum();
+        Ok(sum.into())
+    }
+    fn part_two(schematic: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut sum = 0u64;
+
+        for pos in schematic.grid.positions() {
+            if schematic.grid[pos] != '*' {
+                continue;
+            }
+            let to_process = schematic.adjacent_numbers(pos);
+            if to_process.len() == 2 {
+                let mut product = 1;
+                for number in to_process {
+                    product *= schematic.numbers[number].value;
+                }
+                sum += product;
+            }
+        }
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let result = Day03::solve(input, Part::One);
+        assert_eq!(result, Ok(4361u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let result = Day03::solve(input, Part::Two);
+        assert_eq!(result, Ok(467835u64.into()))
+    }
+    #[test]
+    fn test_number_at_row_end() {
+        let input = "..12
+.*..
+34..";
+        let result = Day03::solve(input, Part::Two);
+        assert_eq!(result, Ok(408u64.into()))
+    }
+}
@@ -0,0 +1,125 @@
+//! Day 4: Scratchcards
+
+use std::collections::*;
+
+use crate::{Answer, Error, Result, Solver};
+
+pub fn get_winners(input: &str) -> Result<HashMap<u32, u32>> {
+    let mut winners = HashMap::new();
+    for line in input.lines() {
+        let mut total_wins = 0;
+        let splits: Vec<_> = line.split([':', '|']).collect();
+        if splits.len() != 3 {
+            return Err(Error::at(
+                input,
+                line,
+                "expected 'Card N: winning numbers | numbers you have'",
+            ));
+        }
+        let card_str = splits[0].split(' ').next_back().unwrap_or(splits[0]);
+        let card_number: u32 = card_str
+            .parse()
+            .map_err(|err| Error::at(input, card_str, format!("bad card number: {err}")))?;
+        let winning_numbers: HashSet<i32> = splits[1].split(' ').flat_map(|s| s.parse()).collect();
+        let numbers_i_have: Vec<i32> = splits[2].split(' ').flat_map(|s| s.parse()).collect();
+        for number in numbers_i_have {
+            if winning_numbers.contains(&number) {
+                total_wins += 1;
+            }
+        }
+        winners.insert(card_number, total_wins);
+        //println!("{card_number} has {total_wins} wins");
+    }
+    Ok(winners)
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Parsed<'a> = HashMap<u32, u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        get_winners(input)
+    }
+    fn part_one(cards: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut sum = 0;
+        for &total in cards.values() {
+            let points = if total > 0 { 2u64.pow(total - 1) } else { 0u64 };
+            //println!("{points} points");
+            sum += points;
+        }
+
+        Ok(sum.into())
+    }
+    fn part_two(cards: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut card_totals: HashMap<u32, u32> = HashMap::with_capacity(cards.len());
+        let (Some(&min), Some(&max)) = (cards.keys().min(), cards.keys().max()) else {
+            return Ok(0u64.into()); // No cards, so no copies either
+        };
+
+        for &card in cards.keys() {
+            card_totals.insert(card, 1); // Every card starts with one copy
+        }
+
+        for card in min..=max {
+            let total = *cards
+                .get(&card)
+                .ok_or_else(|| Error::NoSolution(format!("card {card} is missing")))?;
+            let copies = card_totals[&card];
+            for c in card + 1..=card + total {
+                card_totals.entry(c).and_modify(|cp| *cp += copies);
+            }
+            //println!("Card {} has {} copies", card, card_totals[&card]);
+        }
+
+        Ok((card_totals.values().sum::<u32>() as u64).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let result = Day04::solve(input, Part::One);
+        assert_eq!(result, Ok(13u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let result = Day04::solve(input, Part::Two);
+        assert_eq!(result, Ok(30u64.into()))
+    }
+    #[test]
+    fn test_part_one_missing_separator() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61   61 30 68 82 17 32 24 19";
+        let result = Day04::solve(input, Part::One);
+        assert!(matches!(
+            result,
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ))
+    }
+    #[test]
+    fn test_part_two_no_cards() {
+        let result = Day04::solve("", Part::Two);
+        assert_eq!(result, Ok(0u64.into()))
+    }
+}
@@ -0,0 +1,522 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use std::collections::*;
+use std::fmt::Display;
+use std::ops::{Deref, Range};
+
+use nutype::nutype;
+
+use crate::interval::Interval;
+use crate::{input, Answer, Error, Result, Solver};
+
+#[derive(Clone, Copy)]
+pub struct IdMap<Dest, Src>
+where
+    Dest: Into<u64> + From<u64> + Deref + Copy,
+    Src: Into<u64> + From<u64> + Deref + Copy,
+{
+    destination: Dest,
+    source: Src,
+    count: u64,
+}
+impl<Dest, Src> IdMap<Dest, Src>
+where
+    Dest: Into<u64> + From<u64> + Deref + Copy,
+    Src: Into<u64> + From<u64> + Deref + Copy,
+{
+    pub fn contains(&self, id: &Src) -> bool {
+        (self.source.into()..self.source.into() + self.count).contains(&(*id).into())
+    }
+
+    pub fn map(&self, id: Src) -> Option<Dest> {
+        if self.contains(&id) {
+            Some((id.into() - self.source.into() + self.destination.into()).into())
+        } else {
+            None
+        }
+    }
+
+    pub fn map_chain(maps: impl IntoIterator<Item = Self>, id: u64) -> u64 {
+        maps.into_iter()
+            .flat_map(|m| m.map(id.into()))
+            .next()
+            .unwrap_or(id.into())
+            .into()
+    }
+}
+
+impl<Dest, Src> FromIterator<u64> for IdMap<Dest, Src>
+where
+    Dest: Into<u64> + From<u64> + Deref + Copy,
+    Src: Into<u64> + From<u64> + Deref + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = u64>>(iter_: I) -> Self {
+        let mut iter = iter_.into_iter();
+        Self {
+            destination: iter.next().unwrap_or(u64::MAX).into(),
+            source: iter.next().unwrap_or(u64::MAX).into(),
+            count: iter.next().unwrap_or(u64::MAX),
+        }
+    }
+}
+
+pub trait MappableId<Dest>
+where
+    Dest:
+        Ord + PartialOrd + Eq + PartialEq + From<u64> + Into<u64> + Copy + Clone + Deref + Display,
+    Self:
+        Ord + PartialOrd + Eq + PartialEq + From<u64> + Into<u64> + Copy + Clone + Deref + Display,
+{
+    fn map(self, maps: impl IntoIterator<Item = IdMap<Self, Dest>>) -> Dest {
+        IdMap::map_chain(maps, self.into()).into()
+        //println!("{self} -> {tmp}");
+        //tmp
+    }
+}
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct SeedID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct SoilID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct FertilizerID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct WaterID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct LightID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct TemperatureID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct HumidityID(u64);
+#[nutype(derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, From, Into, Copy, Clone, Deref, Display
+))]
+pub struct LocationID(u64);
+
+impl MappableId<SoilID> for SeedID {}
+impl MappableId<FertilizerID> for SoilID {}
+impl MappableId<WaterID> for FertilizerID {}
+impl MappableId<LightID> for WaterID {}
+impl MappableId<TemperatureID> for LightID {}
+impl MappableId<HumidityID> for TemperatureID {}
+impl MappableId<LocationID> for HumidityID {}
+
+pub fn make_map_vec<Src, Dest>(maps: &[Map]) -> Vec<IdMap<Src, Dest>>
+where
+    Src: Ord + PartialOrd + Eq + PartialEq + From<u64> + Into<u64> + Copy + Clone + Deref,
+    Dest: Ord + PartialOrd + Eq + PartialEq + From<u64> + Into<u64> + Copy + Clone + Deref,
+{
+    maps.iter()
+        .map(|map| [map.start + map.offset, map.start, map.last - map.start + 1])
+        .map(|numbers| numbers.into_iter().map(|num| num as u64))
+        .map(IdMap::from_iter)
+        .collect()
+}
+
+/// The seeds to plant and the seven maps from seed through to location.
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Vec<Map>>,
+}
+
+pub fn parse_almanac(input: &str) -> Result<Almanac> {
+    let blocks = input::blocks(input);
+    let Some((seeds_block, map_blocks)) = blocks.split_first() else {
+        return Err(Error::at(
+            input,
+            input,
+            "expected seeds followed by seven maps",
+        ));
+    };
+
+    let seeds = seeds_block
+        .strip_prefix("seeds:")
+        .ok_or_else(|| Error::at(input, seeds_block, "expected 'seeds:'"))?
+        .split(' ')
+        .filter(|num| !num.is_empty())
+        .map(|num| {
+            num.parse()
+                .map_err(|err| Error::at(input, num, format!("bad seed: {err}")))
+        })
+        .collect::<Result<_>>()?;
+
+    let names = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    if map_blocks.len() != names.len() {
+        return Err(Error::at(
+            input,
+            input,
+            "expected seeds followed by seven maps",
+        ));
+    }
+    let maps = names
+        .into_iter()
+        .zip(map_blocks)
+        .map(|(name, block)| {
+            let mut lines = block.lines();
+            let header = lines.next().unwrap_or(block);
+            if header != format!("{name} map:") {
+                return Err(Error::at(input, header, format!("expected '{name} map:'")));
+            }
+            lines.map(|line| Map::from_string(input, line)).collect()
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn find_min_seed(
+    almanac: &Almanac,
+    seeds: impl IntoIterator<Item = SeedID>,
+) -> Result<LocationID> {
+    let seed_to_soil = make_map_vec::<SeedID, SoilID>(&almanac.maps[0]);
+    let soil_to_fertilizer = make_map_vec::<SoilID, FertilizerID>(&almanac.maps[1]);
+    let fertilizer_to_water = make_map_vec::<FertilizerID, WaterID>(&almanac.maps[2]);
+    let water_to_light = make_map_vec::<WaterID, LightID>(&almanac.maps[3]);
+    let light_to_temperature = make_map_vec::<LightID, TemperatureID>(&almanac.maps[4]);
+    let temperature_to_humidity = make_map_vec::<TemperatureID, HumidityID>(&almanac.maps[5]);
+    let humidity_to_location = make_map_vec::<HumidityID, LocationID>(&almanac.maps[6]);
+
+    seeds
+        .into_iter()
+        .map(|x| x.map(seed_to_soil.iter().copied()))
+        .map(|x| x.map(soil_to_fertilizer.iter().copied()))
+        .map(|x| x.map(fertilizer_to_water.iter().copied()))
+        .map(|x| x.map(water_to_light.iter().copied()))
+        .map(|x| x.map(light_to_temperature.iter().copied()))
+        .map(|x| x.map(temperature_to_humidity.iter().copied()))
+        .map(|x| x.map(humidity_to_location.iter().copied()))
+        .min()
+        .ok_or_else(|| Error::NoSolution("no seeds to plant".to_owned()))
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Rng<'a> {
+    start: i64,
+    last: i64,
+    parent: Option<&'a Rng<'a>>,
+}
+
+impl<'a> Clone for Rng<'a> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            last: self.last,
+            parent: self.parent,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Map {
+    start: i64,
+    last: i64,
+    offset: i64,
+}
+
+impl Map {
+    /// Parses a `destination source length` line, which must be a slice of `input`.
+    pub fn from_string(input: &str, string: &str) -> Result<Map> {
+        let mut splits = string.split(' ');
+        let mut next = |name| -> Result<i64> {
+            let split = splits
+                .next()
+                .ok_or_else(|| Error::at(input, string, format!("missing {name}")))?;
+            split
+                .parse()
+                .map_err(|err| Error::at(input, split, format!("bad {name}: {err}")))
+        };
+        let dest = next("destination")?;
+        let src = next("source")?;
+        let len = next("length")?;
+        if len <= 0 {
+            return Err(Error::at(input, string, "map length must be positive"));
+        }
+
+        Ok(Map {
+            start: src,
+            last: src + len - 1,
+            offset: dest - src,
+        })
+    }
+
+    pub fn range(&self) -> Range<i64> {
+        self.start..self.last + 1
+    }
+}
+
+impl<'a> Rng<'a> {
+    pub fn from_start_len<'b>(start: i64, length: i64) -> Rng<'b> {
+        assert!(length > 0);
+        Rng {
+            start,
+            last: start + length - 1,
+            parent: None,
+        }
+    }
+
+    pub fn new<'b>(start: i64, last: i64, parent: Option<&'b Rng<'b>>) -> Rng<'b> {
+        assert!(start <= last);
+        Rng {
+            start,
+            last,
+            parent,
+        }
+    }
+
+    pub fn range(&self) -> Range<i64> {
+        self.start..self.last + 1
+    }
+
+    pub fn split(&self, others: &Vec<Map>) -> Vec<Rng<'_>> {
+        let mut unprocessed = vec![Interval::new(self.start, self.last)];
+        let mut processed = Vec::new();
+
+        for other in others {
+            let mut new_unprocessed = Vec::new();
+            for to_split in &unprocessed {
+                let split = to_split.split(&Interval::new(other.start, other.last));
+                processed.extend(split.inside.map(|inside| inside.shift(other.offset)));
+                new_unprocessed.extend(split.outside());
+            }
+            unprocessed = new_unprocessed;
+        }
+
+        processed
+            .into_iter()
+            .chain(unprocessed)
+            .map(|interval| Rng::new(interval.start, interval.last, Some(self)))
+            .collect()
+    }
+}
+
+impl<'a> Display for Rng<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parentstr = match self.parent {
+            None => String::new(),
+            Some(parent) => format!("{} -> ", parent),
+        };
+        f.write_fmt(format_args!("{}[{}-{}]", parentstr, self.start, self.last))
+    }
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Parsed<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_almanac(input)
+    }
+    // Inefficient
+    fn part_one(almanac: &Self::Parsed<'_>) -> Result<Answer> {
+        let location: u64 =
+            find_min_seed(almanac, almanac.seeds.iter().copied().map(SeedID::new))?.into();
+        Ok(location.into())
+    }
+    fn part_two(almanac: &Self::Parsed<'_>) -> Result<Answer> {
+        //println!("-------------");
+
+        let seed_ranges = almanac
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| match pair {
+                &[seed, count] if count > 0 => Ok(Rng::from_start_len(seed as i64, count as i64)),
+                _ => Err(Error::NoSolution(format!("seed range {pair:?} is empty"))),
+            })
+            .collect::<Result<HashSet<_>>>()?;
+        //println!("-------------");
+
+        let soil_ranges: HashSet<_> = seed_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[0]))
+            .collect();
+        //println!("-------------");
+
+        let fertilizer_ranges: HashSet<_> = soil_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[1]))
+            .collect();
+        //println!("-------------");
+
+        let water_ranges: HashSet<_> = fertilizer_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[2]))
+            .collect();
+        //println!("-------------");
+
+        let light_ranges: HashSet<_> = water_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[3]))
+            .collect();
+        // println!("-------------");
+
+        let temp_ranges: HashSet<_> = light_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[4]))
+            .collect();
+        // println!("-------------");
+
+        let himdity_ranges: HashSet<_> = temp_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[5]))
+            .collect();
+        // println!("-------------");
+
+        let location_ranges: HashSet<_> = himdity_ranges
+            .iter()
+            .flat_map(|a| a.split(&almanac.maps[6]))
+            .collect();
+        // println!("-------------");
+
+        // for rng in &location_ranges{
+        //     println!("{}", rng);
+        // }
+
+        let min = HashSet::<Rng>::iter(&location_ranges)
+            .map(|x| x.start)
+            .min();
+
+        Ok((min.ok_or_else(|| Error::NoSolution("no seeds to plant".to_owned()))? as u64).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let result = Day05::solve(input, Part::One);
+        assert_eq!(result, Ok(35u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let input = "seeds: 55 13 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let result = Day05::solve(input, Part::Two);
+        assert_eq!(result, Ok(46u64.into()))
+    }
+    #[test]
+    fn test_part_two_bad_map_line() {
+        let result = Day05::solve(
+            "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+
+fertilizer-to-water map:
+49 53 8
+
+water-to-light map:
+88 18 7
+
+light-to-temperature map:
+45 77 23
+
+temperature-to-humidity map:
+0 69 1
+
+humidity-to-location map:
+60 56 99999999999999999999",
+            Part::Two,
+        );
+        assert!(matches!(
+            result,
+            Err(Error::Parse {
+                line: 23,
+                column: 7,
+                ..
+            })
+        ))
+    }
+}
@@ -0,0 +1,185 @@
+//! Day 6: Wait For It
+
+use uom::si::acceleration::kilometer_per_second_squared;
+use uom::si::f64::*;
+use uom::si::length::millimeter;
+use uom::si::time::millisecond;
+
+use crate::{Answer, Error, Result, Solver};
+
+pub struct Race {
+    pub time: Time,
+    pub distance: Length,
+}
+
+type TimeSquared = uom::typenum::Square<Time>;
+impl Race {
+    fn lines(string: &str) -> Result<(&str, &str)> {
+        let mut lines = string.lines();
+        let end = &string[string.len()..];
+        let times = lines
+            .next()
+            .ok_or_else(|| Error::at(string, end, "missing Time line"))?;
+        let distances = lines
+            .next()
+            .ok_or_else(|| Error::at(string, end, "missing Distance line"))?;
+        Ok((times, distances))
+    }
+
+    pub fn from_str_a(string: &str) -> Result<Vec<Self>> {
+        let (times, distances) = Self::lines(string)?;
+        let parse = |line: &str| -> Result<Vec<f64>> {
+            let numbers = line.split(':').next_back().unwrap_or(line);
+            numbers
+                .split_whitespace()
+                .map(|number| {
+                    number
+                        .parse::<f64>()
+                        .map_err(|err| Error::at(string, number, format!("bad number: {err}")))
+                })
+                .collect()
+        };
+        let times = parse(times)?;
+        let lengths = parse(distances)?;
+        if times.len() != lengths.len() {
+            return Err(Error::at(
+                string,
+                distances,
+                format!("expected {} distances, one per time", times.len()),
+            ));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(lengths)
+            .map(|(time, distance)| Race {
+                time: Time::new::<millisecond>(time),
+                distance: Length::new::<millimeter>(distance),
+            })
+            .collect())
+    }
+
+    pub fn from_str_b(string: &str) -> Result<Self> {
+        let (times, distances) = Self::lines(string)?;
+        let parse = |line: &str| {
+            let number = line.split(':').next_back().unwrap_or(line);
+            number
+                .replace(' ', "")
+                .parse::<f64>()
+                .map_err(|err| Error::at(string, number, format!("bad number: {err}")))
+        };
+        let time = Time::new::<millisecond>(parse(times)?);
+        let distance = Length::new::<millimeter>(parse(distances)?);
+        Ok(Self { time, distance })
+    }
+
+    /// Where:
+    ///
+    /// ```text
+    /// a = acceleration
+    /// T = total time
+    /// c = charge time
+    /// d = distance
+    ///
+    /// a*c*(T-c)>d
+    /// a*c*T-a*c*c>d
+    /// c*T-c*c>d/a
+    /// (-1)*c*c + (T) * c + (-d/a) > 0
+    /// ```
+    ///
+    /// Applying the quadratic equation:
+    ///
+    /// ```text
+    /// (-T +- sqrt(T*T-4*d/a)) / (-2) = c
+    /// ```
+    pub fn solve(&self, acceleration: Acceleration) -> u64 {
+        let t_halves: Time = self.time / 2.0;
+        let sqrt_halves: Time =
+            TimeSquared::sqrt(self.time * self.time - 4.0 * self.distance / acceleration) / 2.0;
+        let highest_ms = (t_halves + sqrt_halves).get::<millisecond>();
+        let lowest_ms = (t_halves - sqrt_halves).get::<millisecond>();
+        //println!("[{},{}]", lowest_ms, highest_ms);
+        let result = (highest_ms.floor() - lowest_ms.ceil()) as u64 + 1;
+        //println!("{result}");
+        result
+    }
+}
+
+/// The sheet read both ways: as separate races, and as one race with the spaces removed.
+pub struct Races {
+    pub races: Vec<Race>,
+    pub kerned: Race,
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Parsed<'a> = Races;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(Races {
+            races: Race::from_str_a(input)?,
+            kerned: Race::from_str_b(input)?,
+        })
+    }
+    fn part_one(races: &Self::Parsed<'_>) -> Result<Answer> {
+        // 1 mm / ms^2 == 1 km / s^2
+        let acceleration: Acceleration = Acceleration::new::<kilometer_per_second_squared>(1.0);
+
+        Ok(races
+            .races
+            .iter()
+            .map(|race| race.solve(acceleration))
+            .reduce(|a, b| a * b)
+            .unwrap_or(0)
+            .into())
+    }
+    fn part_two(races: &Self::Parsed<'_>) -> Result<Answer> {
+        // 1 mm / ms^2 == 1 km / s^2
+        let acceleration: Acceleration = Acceleration::new::<kilometer_per_second_squared>(1.0);
+        Ok(races.kerned.solve(acceleration).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+    #[test]
+    fn test_part_one() {
+        let result = Day06::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(288u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day06::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(71503u64.into()))
+    }
+    #[test]
+    fn test_bad_number() {
+        let result = Day06::solve("Time:      7  1x   30\nDistance:  9  40  200", Part::One);
+        assert!(matches!(
+            result,
+            Err(Error::Parse {
+                line: 1,
+                column: 15,
+                ..
+            })
+        ))
+    }
+    #[test]
+    fn test_missing_distance() {
+        let result = Day06::solve("Time:      7  15   30\nDistance:  9  40", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                message: "expected 3 distances, one per time".to_owned()
+            })
+        )
+    }
+}
@@ -0,0 +1,199 @@
+//! Day 7: Camel Cards
+
+use nutype::nutype;
+
+use crate::{Answer, Error, Result, Solver};
+
+/// A card's strength, from a wild joker (1) up to an ace (14).
+#[nutype(
+    validate(greater_or_equal = 1, less_or_equal = 14),
+    derive(
+        Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Deref, Display
+    )
+)]
+pub struct Card(u8);
+
+/// How a `J` is read: as a jack, or as a joker that is the weakest card on
+/// its own but stands in for whatever makes the hand strongest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Jokers {
+    Jacks,
+    Wild,
+}
+
+impl Jokers {
+    pub fn card(self, label: char) -> Option<Card> {
+        let value = match label {
+            '2'..='9' => label.to_digit(10)? as u8,
+            'T' => 10,
+            'J' => match self {
+                Jokers::Jacks => 11,
+                Jokers::Wild => 1,
+            },
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            _ => return None,
+        };
+        Card::try_new(value).ok()
+    }
+}
+
+/// Hand types, weakest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Kind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Kind {
+    fn classify(cards: &[Card; 5], jokers: Jokers) -> Self {
+        let mut counts = [0u8; 15];
+        for card in cards {
+            counts[**card as usize] += 1;
+        }
+        let wild = match jokers {
+            Jokers::Jacks => 0,
+            Jokers::Wild => std::mem::take(&mut counts[1]),
+        };
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        // Jokers always do best joining the largest group
+        match (counts[0] + wild, counts[1]) {
+            (5, _) => Kind::FiveOfAKind,
+            (4, _) => Kind::FourOfAKind,
+            (3, 2) => Kind::FullHouse,
+            (3, _) => Kind::ThreeOfAKind,
+            (2, 2) => Kind::TwoPair,
+            (2, _) => Kind::OnePair,
+            _ => Kind::HighCard,
+        }
+    }
+}
+
+/// A hand of five cards, ordered by kind and then card by card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand {
+    // Field order matters for the derived `Ord`
+    pub kind: Kind,
+    pub cards: [Card; 5],
+}
+
+impl Hand {
+    /// Reads five card labels like `KTJJT`, or `None` if they aren't.
+    pub fn new(labels: &str, jokers: Jokers) -> Option<Self> {
+        let cards: Vec<Card> = labels
+            .chars()
+            .map(|label| jokers.card(label))
+            .collect::<Option<_>>()?;
+        let cards: [Card; 5] = cards.try_into().ok()?;
+        Some(Hand {
+            kind: Kind::classify(&cards, jokers),
+            cards,
+        })
+    }
+}
+
+pub struct Play<'a> {
+    pub cards: &'a str,
+    pub bid: u64,
+}
+
+pub fn parse_plays(input: &str) -> Result<Vec<Play<'_>>> {
+    input
+        .lines()
+        .map(|line| {
+            let (cards, bid_str) = line
+                .split_once(' ')
+                .ok_or_else(|| Error::at(input, line, "expected cards and a bid"))?;
+            if Hand::new(cards, Jokers::Jacks).is_none() {
+                return Err(Error::at(input, cards, "expected five cards"));
+            }
+            let bid = bid_str
+                .parse()
+                .map_err(|err| Error::at(input, bid_str, format!("bad bid: {err}")))?;
+            Ok(Play { cards, bid })
+        })
+        .collect()
+}
+
+/// Each bid times its hand's rank, weakest hand first.
+pub fn total_winnings(plays: &[Play], jokers: Jokers) -> u64 {
+    let mut hands: Vec<_> = plays
+        .iter()
+        .map(|play| {
+            let hand = Hand::new(play.cards, jokers).expect("cards checked while parsing");
+            (hand, play.bid)
+        })
+        .collect();
+    hands.sort_unstable();
+    hands
+        .iter()
+        .zip(1..)
+        .map(|((_, bid), rank)| bid * rank)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Parsed<'a> = Vec<Play<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_plays(input)
+    }
+    fn part_one(plays: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(total_winnings(plays, Jokers::Jacks).into())
+    }
+    fn part_two(plays: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(total_winnings(plays, Jokers::Wild).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    #[test]
+    fn test_part_one() {
+        let result = Day07::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(6440u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day07::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(5905u64.into()))
+    }
+    #[test]
+    fn test_hand_order() {
+        let hand = |labels| Hand::new(labels, Jokers::Jacks).unwrap();
+        assert!(hand("33332") > hand("2AAAA"));
+        assert!(hand("77888") > hand("77788"));
+        assert_eq!(hand("KTJJT").kind, Kind::TwoPair);
+        let wild = Hand::new("KTJJT", Jokers::Wild).unwrap();
+        assert_eq!(wild.kind, Kind::FourOfAKind);
+        assert!(Hand::new("JKKK2", Jokers::Wild) < Hand::new("QQQQ2", Jokers::Wild));
+    }
+    #[test]
+    fn test_bad_card() {
+        let result = Day07::solve("32T3K 765\nT55X5 684", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                message: "expected five cards".to_owned()
+            })
+        )
+    }
+}
@@ -0,0 +1,136 @@
+//! Day 8: Haunted Wasteland
+
+use crate::graph::{NodeMap, Turn};
+use crate::math::lcm_all;
+use crate::{Answer, Error, Result, Solver};
+
+/// The left/right instructions and the network they're followed through.
+pub struct Network<'a> {
+    pub turns: Vec<Turn>,
+    pub nodes: NodeMap<'a>,
+}
+
+pub fn parse_network(input: &str) -> Result<Network<'_>> {
+    let (turns_str, nodes_str) = input
+        .split_once('\n')
+        .ok_or_else(|| Error::at(input, input, "expected instructions and then nodes"))?;
+    let turns = turns_str
+        .trim_end()
+        .char_indices()
+        .map(|(i, label)| {
+            Turn::from_char(label)
+                .ok_or_else(|| Error::at(input, &turns_str[i..], "expected L or R"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if turns.is_empty() {
+        return Err(Error::at(input, turns_str, "no instructions"));
+    }
+    Ok(Network {
+        turns,
+        nodes: NodeMap::parse(input, nodes_str)?,
+    })
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Parsed<'a> = Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_network(input)
+    }
+    fn part_one(network: &Self::Parsed<'_>) -> Result<Answer> {
+        let nodes = &network.nodes;
+        let (start, end) = nodes
+            .find("AAA")
+            .zip(nodes.find("ZZZ"))
+            .ok_or_else(|| Error::NoSolution("need both AAA and ZZZ".to_owned()))?;
+        let cycle = nodes.find_cycle(start, &network.turns, |node| node == end);
+        let steps = cycle
+            .ends
+            .first()
+            .ok_or_else(|| Error::NoSolution("ZZZ can't be reached".to_owned()))?;
+        Ok((*steps).into())
+    }
+    fn part_two(network: &Self::Parsed<'_>) -> Result<Answer> {
+        let nodes = &network.nodes;
+        let periods = (0..nodes.len())
+            .filter(|&node| nodes.name(node).ends_with('A'))
+            .map(|start| {
+                let cycle = nodes.find_cycle(start, &network.turns, |node| {
+                    nodes.name(node).ends_with('Z')
+                });
+                // Puzzle inputs are built so each ghost loops back onto its
+                // end node; anything less regular needs more than an lcm
+                cycle.period().ok_or_else(|| {
+                    Error::NoSolution(format!(
+                        "the ghost starting at {} doesn't loop regularly",
+                        nodes.name(start)
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let steps = lcm_all(periods).ok_or_else(|| {
+            Error::NoSolution("the ghosts take too many steps to count".to_owned())
+        })?;
+        Ok(steps.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        let result = Day08::solve(input, Part::One);
+        assert_eq!(result, Ok(2u64.into()))
+    }
+    #[test]
+    fn test_part_one_repeating() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let result = Day08::solve(input, Part::One);
+        assert_eq!(result, Ok(6u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let result = Day08::solve(input, Part::Two);
+        assert_eq!(result, Ok(6u64.into()))
+    }
+    #[test]
+    fn test_unknown_node() {
+        let result = Day08::solve("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 3,
+                column: 8,
+                message: "unknown node BBB".to_owned()
+            })
+        )
+    }
+}
@@ -0,0 +1,68 @@
+//! Day 9: Mirage Maintenance
+
+use crate::math::{extrapolate_next, extrapolate_previous};
+use crate::{Answer, Error, Result, Solver};
+
+pub fn parse_histories(input: &str) -> Result<Vec<Vec<i64>>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|err| Error::at(input, value, format!("bad value: {err}")))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn sum_extrapolated(histories: &[Vec<i64>], extrapolate: fn(&[i64]) -> Option<i64>) -> Result<i64> {
+    histories.iter().try_fold(0i64, |sum, history| {
+        extrapolate(history)
+            .and_then(|value| sum.checked_add(value))
+            .ok_or_else(|| Error::NoSolution(format!("can't extrapolate {history:?}")))
+    })
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Parsed<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_histories(input)
+    }
+    fn part_one(histories: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(sum_extrapolated(histories, extrapolate_next)?.into())
+    }
+    fn part_two(histories: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(sum_extrapolated(histories, extrapolate_previous)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+    #[test]
+    fn test_part_one() {
+        let result = Day09::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(114i64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day09::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(2i64.into()))
+    }
+    #[test]
+    fn test_negative() {
+        let result = Day09::solve("5 3 1 -1", Part::One);
+        assert_eq!(result, Ok((-3i64).into()))
+    }
+}
@@ -0,0 +1,158 @@
+//! Day 10: Pipe Maze
+
+use crate::grid::{Direction, Grid, Pos};
+use crate::polygon::Polygon;
+use crate::{Answer, Error, Result, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Ground,
+    Start,
+    /// A pipe connecting the two sides it opens onto.
+    Pipe(Direction, Direction),
+}
+
+impl Tile {
+    pub fn from_char(label: char) -> Option<Self> {
+        use Direction::*;
+        Some(match label {
+            '|' => Tile::Pipe(North, South),
+            '-' => Tile::Pipe(East, West),
+            'L' => Tile::Pipe(North, East),
+            'J' => Tile::Pipe(North, West),
+            '7' => Tile::Pipe(South, West),
+            'F' => Tile::Pipe(South, East),
+            '.' => Tile::Ground,
+            'S' => Tile::Start,
+            _ => return None,
+        })
+    }
+
+    /// Where a pipe leads when entered heading `heading`, if it lets it in.
+    fn exit(self, heading: Direction) -> Option<Direction> {
+        let Tile::Pipe(a, b) = self else {
+            return None;
+        };
+        let from = heading.opposite();
+        if a == from {
+            Some(b)
+        } else if b == from {
+            Some(a)
+        } else {
+            None
+        }
+    }
+}
+
+/// Follows the pipes out of the start and back, returning every cell of the
+/// loop in order, starting with the start.
+pub fn trace_loop(grid: &Grid<Tile>) -> Result<Vec<Pos>> {
+    let start = grid
+        .position(|&tile| tile == Tile::Start)
+        .ok_or_else(|| Error::NoSolution("no start tile".to_owned()))?;
+    // Of the (two) pipes leading back to the start, either way round will do
+    'heading: for mut heading in Direction::ALL {
+        let mut cells = vec![start];
+        let mut pos = start;
+        loop {
+            let Some(next) = grid.step(pos, heading) else {
+                continue 'heading;
+            };
+            if next == start {
+                return Ok(cells);
+            }
+            let Some(exit) = grid[next].exit(heading) else {
+                continue 'heading;
+            };
+            cells.push(next);
+            (pos, heading) = (next, exit);
+        }
+    }
+    Err(Error::NoSolution("the start isn't on a loop".to_owned()))
+}
+
+/// Tiles enclosed by the loop through `cells`, or `None` if the loop doubles
+/// back on itself.
+pub fn enclosed(cells: &[Pos]) -> Option<u64> {
+    let vertices = cells
+        .iter()
+        .map(|pos| (pos.row as i64, pos.col as i64))
+        .collect();
+    Polygon::new(vertices).interior_points()
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Parsed<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Grid::parse(input, Tile::from_char)
+    }
+    fn part_one(grid: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok((trace_loop(grid)?.len() as u64 / 2).into())
+    }
+    fn part_two(grid: &Self::Parsed<'_>) -> Result<Answer> {
+        let tiles = enclosed(&trace_loop(grid)?)
+            .ok_or_else(|| Error::NoSolution("the loop doubles back on itself".to_owned()))?;
+        Ok(tiles.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+        let result = Day10::solve(input, Part::One);
+        assert_eq!(result, Ok(8u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        let result = Day10::solve(input, Part::Two);
+        assert_eq!(result, Ok(4u64.into()))
+    }
+    #[test]
+    fn test_part_two_junk_pipes() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        let result = Day10::solve(input, Part::Two);
+        assert_eq!(result, Ok(10u64.into()))
+    }
+    #[test]
+    fn test_bad_tile() {
+        let result = Day10::solve("S-7\n|X|\nL-J", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                message: "unexpected 'X'".to_owned()
+            })
+        )
+    }
+}
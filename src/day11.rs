@@ -0,0 +1,138 @@
+//! Day 11: Cosmic Expansion
+
+use crate::grid::{Grid, Pos};
+use crate::{Answer, Error, Params, Result, Solver};
+
+/// The galaxies in the image, and how many times wider part two makes each
+/// empty row and column.
+pub struct Universe {
+    pub galaxies: Vec<Pos>,
+    pub expansion: u64,
+}
+
+/// Each coordinate after every empty line before it is widened to `factor`
+/// lines. `coords` must be sorted. `None` if that overflows.
+fn expand(coords: &[usize], factor: u64) -> Option<Vec<u64>> {
+    // Lines before a coordinate that have no galaxy on them
+    let mut occupied = 0;
+    let mut previous = None;
+    coords
+        .iter()
+        .map(|&coord| {
+            if previous != Some(coord) {
+                occupied += 1;
+                previous = Some(coord);
+            }
+            let empty = (coord + 1 - occupied) as u64;
+            empty.checked_mul(factor - 1)?.checked_add(coord as u64)
+        })
+        .collect()
+}
+
+/// Sum of the distances between every pair of `coords`, which must be
+/// sorted. `None` if that overflows.
+fn pairwise_distance(coords: &[u64]) -> Option<u64> {
+    let mut before = 0u64;
+    let mut total = 0u64;
+    for (&coord, count) in coords.iter().zip(0u64..) {
+        let distance = coord.checked_mul(count)? - before;
+        total = total.checked_add(distance)?;
+        before = before.checked_add(coord)?;
+    }
+    Some(total)
+}
+
+/// Sum of the shortest paths between every pair of galaxies, with empty rows
+/// and columns `factor` times as wide. `None` if the sum doesn't fit a `u64`.
+pub fn galaxy_distances(galaxies: &[Pos], factor: u64) -> Option<u64> {
+    let mut rows: Vec<_> = galaxies.iter().map(|galaxy| galaxy.row).collect();
+    let mut cols: Vec<_> = galaxies.iter().map(|galaxy| galaxy.col).collect();
+    rows.sort_unstable();
+    cols.sort_unstable();
+    pairwise_distance(&expand(&rows, factor)?)?
+        .checked_add(pairwise_distance(&expand(&cols, factor)?)?)
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Parsed<'a> = Universe;
+
+    const PARAMS: &'static [&'static str] = &["expansion"];
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>> {
+        let expansion = params.get("expansion", 1_000_000)?;
+        if expansion == 0 {
+            return Err(Error::Param("expansion must be at least 1".to_owned()));
+        }
+        let image = Grid::parse(input, |label| match label {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(Universe {
+            galaxies: image.positions().filter(|&pos| image[pos]).collect(),
+            expansion,
+        })
+    }
+    fn part_one(universe: &Self::Parsed<'_>) -> Result<Answer> {
+        galaxy_distances(&universe.galaxies, 2)
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("galaxy distances overflow".to_owned()))
+    }
+    fn part_two(universe: &Self::Parsed<'_>) -> Result<Answer> {
+        galaxy_distances(&universe.galaxies, universe.expansion)
+            .map(Answer::from)
+            .ok_or_else(|| {
+                Error::Param(format!(
+                    "expansion={} makes the galaxy distances overflow",
+                    universe.expansion
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    #[test]
+    fn test_part_one() {
+        let result = Day11::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(374u64.into()))
+    }
+    #[test]
+    fn test_part_two_expansion() {
+        for (expansion, expected) in [("10", 1030u64), ("100", 8410)] {
+            let params = Params::from_iter([("expansion", expansion)]);
+            let result = Day11::solve_with(INPUT, &params, Part::Two);
+            assert_eq!(result, Ok(expected.into()))
+        }
+    }
+    #[test]
+    fn test_overflowing_expansion() {
+        let params = Params::from_iter([("expansion", u64::MAX.to_string())]);
+        let result = Day11::solve_with(INPUT, &params, Part::Two);
+        assert!(matches!(result, Err(Error::Param(_))));
+    }
+    #[test]
+    fn test_bad_expansion() {
+        let params = Params::from_iter([("expansion", "lots")]);
+        let result = Day11::solve_with(INPUT, &params, Part::Two);
+        assert!(matches!(result, Err(Error::Param(_))));
+    }
+}
@@ -0,0 +1,153 @@
+//! Day 12: Hot Springs
+
+use crate::memo::Memo;
+use crate::{Answer, Error, Result, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    pub fn from_char(label: char) -> Option<Self> {
+        match label {
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// A condition record: the springs, and the sizes of the runs of damaged ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    pub fn parse(input: &str, line: &str) -> Result<Self> {
+        let (springs_str, groups_str) = line
+            .split_once(' ')
+            .ok_or_else(|| Error::at(input, line, "expected springs and group sizes"))?;
+        let springs = springs_str
+            .char_indices()
+            .map(|(i, label)| {
+                Spring::from_char(label)
+                    .ok_or_else(|| Error::at(input, &springs_str[i..], "expected '.', '#' or '?'"))
+            })
+            .collect::<Result<_>>()?;
+        let groups = groups_str
+            .split(',')
+            .map(|size| {
+                size.parse()
+                    .map_err(|err| Error::at(input, size, format!("bad group size: {err}")))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Record { springs, groups })
+    }
+
+    /// The record folded out `times` times, with unknown springs between copies.
+    pub fn unfold(&self, times: usize) -> Self {
+        let mut springs = Vec::new();
+        for copy in 0..times {
+            if copy > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend(&self.springs);
+        }
+        Record {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    /// How many ways the unknown springs can be filled in to fit the groups.
+    pub fn arrangements(&self) -> u64 {
+        count(&mut Memo::new(), &self.springs, &self.groups)
+    }
+}
+
+/// Arrangements of `springs` fitting `groups`. Both only ever shrink from the
+/// front, so their lengths are enough to key the memo.
+fn count(memo: &mut Memo<(usize, usize), u64>, springs: &[Spring], groups: &[usize]) -> u64 {
+    memo.get_or_insert_with((springs.len(), groups.len()), |memo| {
+        let Some((&size, rest)) = groups.split_first() else {
+            return (!springs.contains(&Spring::Damaged)).into();
+        };
+        let Some(&first) = springs.first() else {
+            return 0;
+        };
+        let mut total = 0;
+        if first != Spring::Damaged {
+            total += count(memo, &springs[1..], groups);
+        }
+        if first != Spring::Operational
+            && springs.len() >= size
+            && !springs[..size].contains(&Spring::Operational)
+            && springs.get(size) != Some(&Spring::Damaged)
+        {
+            // Skip the group and the operational spring that has to follow it
+            total += count(memo, springs.get(size + 1..).unwrap_or_default(), rest);
+        }
+        total
+    })
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Parsed<'a> = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input
+            .lines()
+            .map(|line| Record::parse(input, line))
+            .collect()
+    }
+    fn part_one(records: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(records.iter().map(Record::arrangements).sum::<u64>().into())
+    }
+    fn part_two(records: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(records
+            .iter()
+            .map(|record| record.unfold(5).arrangements())
+            .sum::<u64>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+    #[test]
+    fn test_part_one() {
+        let result = Day12::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(21u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day12::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(525152u64.into()))
+    }
+    #[test]
+    fn test_rows() {
+        let expected = [(1, 1), (4, 16384), (1, 1), (1, 16), (4, 2500), (10, 506250)];
+        for (line, (folded, unfolded)) in INPUT.lines().zip(expected) {
+            let record = Record::parse(line, line).unwrap();
+            assert_eq!(record.arrangements(), folded, "{line}");
+            assert_eq!(record.unfold(5).arrangements(), unfolded, "{line}");
+        }
+    }
+}
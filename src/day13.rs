@@ -0,0 +1,131 @@
+//! Day 13: Point of Incidence
+
+use crate::grid::{Grid, Pos};
+use crate::{input, Answer, Error, Result, Solver};
+
+/// A pattern of ash (`false`) and rocks (`true`).
+pub type Pattern = Grid<bool>;
+
+/// The first line between `0..len` that mirrors the pattern with exactly
+/// `smudges` mismatched cells, counted as how many lines lie before it.
+/// `differs(a, b)` counts the cells that differ between lines `a` and `b`.
+fn mirror_line(
+    len: usize,
+    smudges: usize,
+    differs: impl Fn(usize, usize) -> usize,
+) -> Option<usize> {
+    (1..len).find(|&line| {
+        let mut mismatches = 0;
+        for distance in 0..line.min(len - line) {
+            mismatches += differs(line - 1 - distance, line + distance);
+            if mismatches > smudges {
+                return false;
+            }
+        }
+        mismatches == smudges
+    })
+}
+
+/// Columns left of a vertical mirror, or 100 times the rows above a
+/// horizontal one, when the pattern has exactly `smudges` cells off.
+pub fn summarize(pattern: &Pattern, smudges: usize) -> Option<u64> {
+    let column = mirror_line(pattern.width(), smudges, |a, b| {
+        (0..pattern.height())
+            .filter(|&row| pattern[Pos::new(row, a)] != pattern[Pos::new(row, b)])
+            .count()
+    });
+    let row = || {
+        mirror_line(pattern.height(), smudges, |a, b| {
+            (0..pattern.width())
+                .filter(|&col| pattern[Pos::new(a, col)] != pattern[Pos::new(b, col)])
+                .count()
+        })
+    };
+    match column {
+        Some(column) => Some(column as u64),
+        None => row().map(|row| 100 * row as u64),
+    }
+}
+
+fn summarize_all(patterns: &[Pattern], smudges: usize) -> Result<u64> {
+    patterns
+        .iter()
+        .zip(1..)
+        .map(|(pattern, number)| {
+            summarize(pattern, smudges).ok_or_else(|| {
+                Error::NoSolution(format!(
+                    "pattern {number} has no mirror with {smudges} smudges"
+                ))
+            })
+        })
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Parsed<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input::blocks(input)
+            .into_iter()
+            .map(|block| {
+                Grid::parse_block(input, block, |label| match label {
+                    '#' => Some(true),
+                    '.' => Some(false),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+    fn part_one(patterns: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(summarize_all(patterns, 0)?.into())
+    }
+    fn part_two(patterns: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(summarize_all(patterns, 1)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    #[test]
+    fn test_part_one() {
+        let result = Day13::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(405u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day13::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(400u64.into()))
+    }
+    #[test]
+    fn test_bad_cell_in_second_block() {
+        let result = Day13::solve("#.\n#.\n\n\n.#\n.x", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 6,
+                column: 2,
+                message: "unexpected 'x'".to_owned()
+            })
+        )
+    }
+}
@@ -0,0 +1,150 @@
+//! Day 14: Parabolic Reflector Dish
+
+use crate::grid::{Direction, Grid, Pos};
+use crate::simulate::nth_state;
+use crate::{Answer, Result, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Empty,
+    /// Rolls when the platform is tilted.
+    Round,
+    /// Stays put, and stops round rocks.
+    Cube,
+}
+
+impl Rock {
+    pub fn from_char(label: char) -> Option<Self> {
+        match label {
+            '.' => Some(Rock::Empty),
+            'O' => Some(Rock::Round),
+            '#' => Some(Rock::Cube),
+            _ => None,
+        }
+    }
+}
+
+pub type Platform = Grid<Rock>;
+
+/// The lines rocks roll along when tilting towards `direction`, each starting
+/// from the edge they roll towards.
+fn lanes(platform: &Platform, direction: Direction) -> Vec<Vec<Pos>> {
+    let (width, height) = (platform.width(), platform.height());
+    match direction {
+        Direction::North => (0..width)
+            .map(|col| (0..height).map(|row| Pos::new(row, col)).collect())
+            .collect(),
+        Direction::South => (0..width)
+            .map(|col| (0..height).rev().map(|row| Pos::new(row, col)).collect())
+            .collect(),
+        Direction::West => (0..height)
+            .map(|row| (0..width).map(|col| Pos::new(row, col)).collect())
+            .collect(),
+        Direction::East => (0..height)
+            .map(|row| (0..width).rev().map(|col| Pos::new(row, col)).collect())
+            .collect(),
+    }
+}
+
+/// Rolls every round rock as far towards `direction` as it goes.
+pub fn tilt(platform: &mut Platform, direction: Direction) {
+    for lane in lanes(platform, direction) {
+        let mut free = 0;
+        for (index, &pos) in lane.iter().enumerate() {
+            match platform[pos] {
+                Rock::Cube => free = index + 1,
+                Rock::Round => {
+                    platform[pos] = Rock::Empty;
+                    platform[lane[free]] = Rock::Round;
+                    free += 1;
+                }
+                Rock::Empty => {}
+            }
+        }
+    }
+}
+
+/// One spin cycle: tilts north, west, south, then east.
+pub fn spin(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    for direction in [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ] {
+        tilt(&mut platform, direction);
+    }
+    platform
+}
+
+/// Load on the north support beams: each round rock counts its rows from the south edge.
+pub fn north_load(platform: &Platform) -> u64 {
+    platform
+        .positions()
+        .filter(|&pos| platform[pos] == Rock::Round)
+        .map(|pos| (platform.height() - pos.row) as u64)
+        .sum()
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Parsed<'a> = Platform;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Grid::parse(input, Rock::from_char)
+    }
+    fn part_one(platform: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut platform = platform.clone();
+        tilt(&mut platform, Direction::North);
+        Ok(north_load(&platform).into())
+    }
+    fn part_two(platform: &Self::Parsed<'_>) -> Result<Answer> {
+        let platform = nth_state(platform.clone(), 1_000_000_000, spin);
+        Ok(north_load(&platform).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    #[test]
+    fn test_part_one() {
+        let result = Day14::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(136u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day14::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(64u64.into()))
+    }
+    #[test]
+    fn test_spin() {
+        let after = ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+        let platform = Day14::parse(INPUT).unwrap();
+        let expected = Day14::parse(after).unwrap();
+        assert_eq!(nth_state(platform, 3, spin), expected);
+    }
+}
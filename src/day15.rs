@@ -0,0 +1,143 @@
+//! Day 15: Lens Library
+
+use crate::{Answer, Error, Result, Solver};
+
+/// The Holiday ASCII String Helper algorithm.
+pub fn hash(string: &str) -> u8 {
+    string
+        .bytes()
+        .fold(0u8, |value, byte| value.wrapping_add(byte).wrapping_mul(17))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `label=N`: put in a lens, replacing any with the same label.
+    Insert(u8),
+    /// `label-`: take out the lens with the label, if any.
+    Remove,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub text: &'a str,
+    pub label: &'a str,
+    pub operation: Operation,
+}
+
+pub fn parse_steps(input: &str) -> Result<Vec<Step<'_>>> {
+    input
+        .trim_end()
+        .split(',')
+        .map(|text| {
+            let text = text.trim_matches(['\r', '\n']);
+            let (label, operation) = if let Some(label) = text.strip_suffix('-') {
+                (label, Operation::Remove)
+            } else {
+                let (label, focal) = text
+                    .split_once('=')
+                    .ok_or_else(|| Error::at(input, text, "expected 'label=N' or 'label-'"))?;
+                let focal = focal
+                    .parse()
+                    .map_err(|err| Error::at(input, focal, format!("bad focal length: {err}")))?;
+                (label, Operation::Insert(focal))
+            };
+            Ok(Step {
+                text,
+                label,
+                operation,
+            })
+        })
+        .collect()
+}
+
+/// 256 boxes of lenses, each kept in the order they went in.
+pub struct Boxes<'a> {
+    boxes: Vec<Vec<(&'a str, u8)>>,
+}
+
+impl<'a> Boxes<'a> {
+    pub fn new() -> Self {
+        Boxes {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+
+    pub fn apply(&mut self, step: &Step<'a>) {
+        let lenses = &mut self.boxes[hash(step.label) as usize];
+        let existing = lenses.iter().position(|&(label, _)| label == step.label);
+        match (step.operation, existing) {
+            (Operation::Insert(focal), Some(index)) => lenses[index].1 = focal,
+            (Operation::Insert(focal), None) => lenses.push((step.label, focal)),
+            (Operation::Remove, Some(index)) => {
+                lenses.remove(index);
+            }
+            (Operation::Remove, None) => {}
+        }
+    }
+
+    /// Each lens's box number plus one, times its slot, times its focal length.
+    pub fn focusing_power(&self) -> u64 {
+        self.boxes
+            .iter()
+            .zip(1..)
+            .flat_map(|(lenses, number)| {
+                lenses
+                    .iter()
+                    .zip(1..)
+                    .map(move |(&(_, focal), slot)| number * slot * focal as u64)
+            })
+            .sum()
+    }
+}
+
+impl Default for Boxes<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Parsed<'a> = Vec<Step<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_steps(input)
+    }
+    fn part_one(steps: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(steps
+            .iter()
+            .map(|step| hash(step.text) as u64)
+            .sum::<u64>()
+            .into())
+    }
+    fn part_two(steps: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut boxes = Boxes::new();
+        for step in steps {
+            boxes.apply(step);
+        }
+        Ok(boxes.focusing_power().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52)
+    }
+    #[test]
+    fn test_part_one() {
+        let result = Day15::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(1320u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day15::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(145u64.into()))
+    }
+}
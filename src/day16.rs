@@ -0,0 +1,139 @@
+//! Day 16: The Floor Will Be Lava
+
+use crate::grid::{Direction, Grid, Pos};
+use crate::{Answer, Error, Result, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    /// `/`
+    Mirror,
+    /// `\`
+    BackMirror,
+    /// `|`
+    VerticalSplitter,
+    /// `-`
+    HorizontalSplitter,
+}
+
+impl Tile {
+    pub fn from_char(label: char) -> Option<Self> {
+        match label {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::Mirror),
+            '\\' => Some(Tile::BackMirror),
+            '|' => Some(Tile::VerticalSplitter),
+            '-' => Some(Tile::HorizontalSplitter),
+            _ => None,
+        }
+    }
+
+    /// The one or two headings a beam leaves with after entering heading `heading`.
+    fn redirect(self, heading: Direction) -> (Direction, Option<Direction>) {
+        use Direction::*;
+        match (self, heading) {
+            (Tile::Mirror, North) => (East, None),
+            (Tile::Mirror, East) => (North, None),
+            (Tile::Mirror, South) => (West, None),
+            (Tile::Mirror, West) => (South, None),
+            (Tile::BackMirror, North) => (West, None),
+            (Tile::BackMirror, West) => (North, None),
+            (Tile::BackMirror, South) => (East, None),
+            (Tile::BackMirror, East) => (South, None),
+            (Tile::VerticalSplitter, East | West) => (North, Some(South)),
+            (Tile::HorizontalSplitter, North | South) => (East, Some(West)),
+            _ => (heading, None),
+        }
+    }
+}
+
+pub type Contraption = Grid<Tile>;
+
+/// How many tiles a beam entering at `start` heading `heading` energizes.
+/// Each (position, heading) state is followed at most once, so beams caught
+/// in loops end.
+pub fn energized(contraption: &Contraption, start: Pos, heading: Direction) -> usize {
+    // One bit per heading a beam has passed through each tile with
+    let mut seen = Grid::new(contraption.width(), contraption.height(), 0u8);
+    let mut beams = vec![(start, heading)];
+    while let Some((pos, heading)) = beams.pop() {
+        let bit = 1 << heading as u8;
+        if !contraption.contains(pos) || seen[pos] & bit != 0 {
+            continue;
+        }
+        seen[pos] |= bit;
+        let (first, second) = contraption[pos].redirect(heading);
+        for heading in [Some(first), second].into_iter().flatten() {
+            if let Some(next) = contraption.step(pos, heading) {
+                beams.push((next, heading));
+            }
+        }
+    }
+    seen.positions().filter(|&pos| seen[pos] != 0).count()
+}
+
+/// Every way a beam can enter from the edge: a tile on the edge and the
+/// heading away from it.
+pub fn edge_entries(contraption: &Contraption) -> impl Iterator<Item = (Pos, Direction)> + '_ {
+    let (width, height) = (contraption.width(), contraption.height());
+    let columns = (0..width).flat_map(move |col| {
+        [
+            (Pos::new(0, col), Direction::South),
+            (Pos::new(height.saturating_sub(1), col), Direction::North),
+        ]
+    });
+    let rows = (0..height).flat_map(move |row| {
+        [
+            (Pos::new(row, 0), Direction::East),
+            (Pos::new(row, width.saturating_sub(1)), Direction::West),
+        ]
+    });
+    columns.chain(rows)
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Parsed<'a> = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Grid::parse(input, Tile::from_char)
+    }
+    fn part_one(contraption: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok((energized(contraption, Pos::new(0, 0), Direction::East) as u64).into())
+    }
+    fn part_two(contraption: &Self::Parsed<'_>) -> Result<Answer> {
+        edge_entries(contraption)
+            .map(|(start, heading)| energized(contraption, start, heading) as u64)
+            .max()
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("empty contraption".to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    #[test]
+    fn test_part_one() {
+        let result = Day16::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(46u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day16::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(51u64.into()))
+    }
+}
@@ -0,0 +1,112 @@
+//! Day 17: Clumsy Crucible
+
+use crate::grid::{Direction, Grid, Pos};
+use crate::search::dijkstra;
+use crate::{Answer, Error, Result, Solver};
+
+/// Where a crucible is, which way it's heading, and how many blocks it has
+/// gone in a straight line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Crucible {
+    pub pos: Pos,
+    pub heading: Direction,
+    pub run: u8,
+}
+
+/// The least heat lost getting a crucible from the top left to the bottom
+/// right, when it has to go `min_run` blocks before turning or stopping and
+/// can't go more than `max_run` without turning.
+pub fn least_heat_loss(city: &Grid<u8>, min_run: u8, max_run: u8) -> Option<u64> {
+    let goal = Pos::new(city.height().checked_sub(1)?, city.width().checked_sub(1)?);
+    let starts = [Direction::East, Direction::South].map(|heading| Crucible {
+        pos: Pos::new(0, 0),
+        heading,
+        run: 0,
+    });
+    let neighbours = |crucible: &Crucible| {
+        let mut headings = Vec::with_capacity(3);
+        if crucible.run < max_run {
+            headings.push(crucible.heading);
+        }
+        if crucible.run >= min_run || crucible.run == 0 {
+            headings.push(crucible.heading.turn_left());
+            headings.push(crucible.heading.turn_right());
+        }
+        headings
+            .into_iter()
+            .filter_map(|heading| {
+                let pos = city.step(crucible.pos, heading)?;
+                let run = if heading == crucible.heading {
+                    crucible.run + 1
+                } else {
+                    1
+                };
+                Some((Crucible { pos, heading, run }, city[pos] as u64))
+            })
+            .collect::<Vec<_>>()
+    };
+    let is_goal = |crucible: &Crucible| crucible.pos == goal && crucible.run >= min_run;
+    dijkstra(starts, neighbours, is_goal).map(|(heat, _)| heat)
+}
+
+fn answer(city: &Grid<u8>, min_run: u8, max_run: u8) -> Result<Answer> {
+    least_heat_loss(city, min_run, max_run)
+        .map(Answer::from)
+        .ok_or_else(|| Error::NoSolution("the crucible can't reach the factory".to_owned()))
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Parsed<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Grid::parse(input, |label| Some(label.to_digit(10)? as u8))
+    }
+    fn part_one(city: &Self::Parsed<'_>) -> Result<Answer> {
+        answer(city, 1, 3)
+    }
+    fn part_two(city: &Self::Parsed<'_>) -> Result<Answer> {
+        answer(city, 4, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    #[test]
+    fn test_part_one() {
+        let result = Day17::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(102u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day17::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(94u64.into()))
+    }
+    #[test]
+    fn test_part_two_long_runs() {
+        let input = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+        let result = Day17::solve(input, Part::Two);
+        assert_eq!(result, Ok(71u64.into()))
+    }
+}
@@ -0,0 +1,167 @@
+//! Day 18: Lavaduct Lagoon
+
+use crate::grid::Direction;
+use crate::polygon::Polygon;
+use crate::{Answer, Error, Result, Solver};
+
+pub type Step = (Direction, u64);
+
+/// The dig plan read both ways. A bad color is kept as part two's error, so
+/// part one doesn't fail over colors it never uses.
+pub struct DigPlan {
+    pub steps: Vec<Step>,
+    pub hex_steps: Result<Vec<Step>>,
+}
+
+/// Reads the direction and length from lines like `R 6 (#70c710)`.
+pub fn parse_steps(input: &str) -> Result<Vec<Step>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut fields = line.split(' ');
+            let direction_str = fields.next().unwrap_or(line);
+            let direction = match direction_str {
+                "U" => Direction::North,
+                "R" => Direction::East,
+                "D" => Direction::South,
+                "L" => Direction::West,
+                _ => return Err(Error::at(input, direction_str, "expected U, R, D or L")),
+            };
+            let length_str = fields
+                .next()
+                .ok_or_else(|| Error::at(input, line, "missing length"))?;
+            let length = length_str
+                .parse()
+                .map_err(|err| Error::at(input, length_str, format!("bad length: {err}")))?;
+            Ok((direction, length))
+        })
+        .collect()
+}
+
+/// Reads the steps hidden in the colors instead: five hex digits of length,
+/// then one of direction (0 to 3 for R, D, L and U).
+pub fn parse_hex_steps(input: &str) -> Result<Vec<Step>> {
+    input
+        .lines()
+        .map(|line| {
+            let code = line
+                .split(' ')
+                .nth(2)
+                .and_then(|color| color.strip_prefix("(#"))
+                .and_then(|color| color.strip_suffix(')'))
+                .filter(|code| code.len() == 6 && code.is_ascii())
+                .ok_or_else(|| Error::at(input, line, "expected a color like (#70c710)"))?;
+            let (length_str, direction_str) = code.split_at(5);
+            let length = u64::from_str_radix(length_str, 16)
+                .map_err(|err| Error::at(input, length_str, format!("bad length: {err}")))?;
+            let direction = match direction_str {
+                "0" => Direction::East,
+                "1" => Direction::South,
+                "2" => Direction::West,
+                "3" => Direction::North,
+                _ => return Err(Error::at(input, direction_str, "expected 0, 1, 2 or 3")),
+            };
+            Ok((direction, length))
+        })
+        .collect()
+}
+
+/// Cubic meters of lava the dug-out lagoon holds, trench included.
+pub fn lagoon_size(steps: &[Step]) -> Result<u64> {
+    let trench = Polygon::from_steps(steps.iter().copied()).ok_or_else(|| {
+        Error::NoSolution("the trench doesn't loop back to where it starts".to_owned())
+    })?;
+    trench.lattice_points().ok_or_else(|| {
+        Error::NoSolution("the trench doubles back on itself or is too big to measure".to_owned())
+    })
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Parsed<'a> = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(DigPlan {
+            steps: parse_steps(input)?,
+            hex_steps: parse_hex_steps(input),
+        })
+    }
+    fn part_one(plan: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(lagoon_size(&plan.steps)?.into())
+    }
+    fn part_two(plan: &Self::Parsed<'_>) -> Result<Answer> {
+        let steps = plan.hex_steps.as_ref().map_err(Clone::clone)?;
+        Ok(lagoon_size(steps)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+    #[test]
+    fn test_part_one() {
+        let result = Day18::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(62u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day18::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(952408144115u64.into()))
+    }
+    #[test]
+    fn test_part_one_ignores_colors() {
+        let input = "R 2 (#70c710)\nD 2\nL 2 (#5713f0)\nU 2 (#zzzzzz)";
+        assert_eq!(Day18::solve(input, Part::One), Ok(9u64.into()));
+        assert!(matches!(
+            Day18::solve(input, Part::Two),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+    #[test]
+    fn test_hex_steps() {
+        let steps = parse_hex_steps("R 6 (#70c710)\nD 5 (#0dc571)").unwrap();
+        assert_eq!(
+            steps,
+            [(Direction::East, 461937), (Direction::South, 56407)]
+        )
+    }
+    #[test]
+    fn test_degenerate_trench() {
+        let input = "R 5 (#000050)\nL 5 (#000052)";
+        assert!(matches!(
+            Day18::solve(input, Part::One),
+            Err(Error::NoSolution(_))
+        ));
+        assert!(matches!(
+            Day18::solve(input, Part::Two),
+            Err(Error::NoSolution(_))
+        ));
+    }
+    #[test]
+    fn test_unclosed_trench() {
+        let result = Day18::solve("R 2 (#70c710)\nD 2 (#0dc571)\nL 2 (#5713f0)", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::NoSolution(
+                "the trench doesn't loop back to where it starts".to_owned()
+            ))
+        )
+    }
+}
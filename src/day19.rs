@@ -0,0 +1,398 @@
+//! Day 19: Aplenty
+
+use std::collections::HashMap;
+
+use crate::interval::Interval;
+use crate::{input, Answer, Error, Result, Solver};
+
+/// A part's `x`, `m`, `a` and `s` ratings, in that order.
+pub type Ratings = [i64; 4];
+
+/// Every combination of ratings with each rating in its own interval.
+pub type RatingRanges = [Interval; 4];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+impl<'a> Target<'a> {
+    fn parse(name: &'a str) -> Self {
+        match name {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => Target::Workflow(name),
+        }
+    }
+}
+
+/// A rule sending parts whose rating `category` lies in `accepts` on to
+/// `target`. The last rule of a workflow accepts everything.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub category: usize,
+    pub accepts: Interval,
+    pub target: Target<'a>,
+}
+
+pub struct System<'a> {
+    pub workflows: HashMap<&'a str, Vec<Rule<'a>>>,
+    pub parts: Vec<Ratings>,
+}
+
+fn category(input: &str, name: &str) -> Result<usize> {
+    ["x", "m", "a", "s"]
+        .iter()
+        .position(|&category| category == name)
+        .ok_or_else(|| Error::at(input, name, "expected x, m, a or s"))
+}
+
+fn parse_rating(input: &str, rating: &str) -> Result<i64> {
+    rating
+        .parse()
+        .map_err(|err| Error::at(input, rating, format!("bad rating: {err}")))
+}
+
+/// Parses a rule like `a<2006:qkq`, or a bare target like `rfg`.
+fn parse_rule<'a>(input: &str, text: &'a str) -> Result<Rule<'a>> {
+    let Some((condition, target)) = text.split_once(':') else {
+        return Ok(Rule {
+            category: 0,
+            accepts: Interval::new(i64::MIN, i64::MAX),
+            target: Target::parse(text),
+        });
+    };
+    let split = condition
+        .find(['<', '>'])
+        .ok_or_else(|| Error::at(input, condition, "expected '<' or '>'"))?;
+    let (name, rest) = condition.split_at(split);
+    let value = parse_rating(input, &rest[1..])?;
+    let accepts = match &rest[..1] {
+        "<" => value
+            .checked_sub(1)
+            .and_then(|last| Interval::checked(i64::MIN, last)),
+        _ => value
+            .checked_add(1)
+            .and_then(|start| Interval::checked(start, i64::MAX)),
+    }
+    .ok_or_else(|| Error::at(input, rest, "condition can never hold"))?;
+    Ok(Rule {
+        category: category(input, name)?,
+        accepts,
+        target: Target::parse(target),
+    })
+}
+
+pub fn parse_system(input: &str) -> Result<System<'_>> {
+    let blocks = input::blocks(input);
+    let [workflows_block, parts_block] = blocks[..] else {
+        return Err(Error::at(input, input, "expected workflows, then parts"));
+    };
+
+    let mut workflows = HashMap::new();
+    let mut names = Vec::new();
+    for line in workflows_block.lines() {
+        let (name, rules) = line
+            .strip_suffix('}')
+            .and_then(|line| line.split_once('{'))
+            .ok_or_else(|| Error::at(input, line, "expected 'name{rules}'"))?;
+        let rules: Vec<&str> = rules.split(',').collect();
+        let (last, conditional) = rules.split_last().expect("split yields at least one");
+        if last.contains(':') {
+            return Err(Error::at(
+                input,
+                line,
+                "expected a bare target as the last rule",
+            ));
+        }
+        if conditional.iter().any(|rule| !rule.contains(':')) {
+            return Err(Error::at(
+                input,
+                line,
+                "expected a condition on every rule but the last",
+            ));
+        }
+        let rules = rules
+            .into_iter()
+            .map(|rule| parse_rule(input, rule))
+            .collect::<Result<Vec<_>>>()?;
+        workflows.insert(name, rules);
+        names.push(name);
+    }
+    for rule in names.iter().flat_map(|name| &workflows[name]) {
+        if let Target::Workflow(name) = rule.target {
+            if !workflows.contains_key(name) {
+                return Err(Error::at(input, name, format!("unknown workflow {name}")));
+            }
+        }
+    }
+    if !workflows.contains_key("in") {
+        return Err(Error::at(input, workflows_block, "no 'in' workflow"));
+    }
+    check_acyclic(input, &names, &workflows)?;
+
+    let parts = parts_block
+        .lines()
+        .map(|line| {
+            let mut ratings = [None; 4];
+            let fields = line
+                .strip_prefix('{')
+                .and_then(|line| line.strip_suffix('}'))
+                .ok_or_else(|| Error::at(input, line, "expected '{x=..,m=..,a=..,s=..}'"))?;
+            for field in fields.split(',') {
+                let (name, rating) = field
+                    .split_once('=')
+                    .ok_or_else(|| Error::at(input, field, "expected 'category=rating'"))?;
+                let slot = &mut ratings[category(input, name)?];
+                if slot.is_some() {
+                    return Err(Error::at(input, field, "category rated twice"));
+                }
+                *slot = Some(parse_rating(input, rating)?);
+            }
+            let [Some(x), Some(m), Some(a), Some(s)] = ratings else {
+                return Err(Error::at(input, line, "expected '{x=..,m=..,a=..,s=..}'"));
+            };
+            Ok([x, m, a, s])
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(System { workflows, parts })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+/// Rejects workflows that can send a part back to themselves, which would
+/// never finish sorting it. The error points at the workflow whose rule
+/// closes the loop.
+fn check_acyclic<'a>(
+    input: &str,
+    names: &[&'a str],
+    workflows: &HashMap<&'a str, Vec<Rule<'a>>>,
+) -> Result<()> {
+    fn visit<'a>(
+        input: &str,
+        name: &'a str,
+        workflows: &HashMap<&'a str, Vec<Rule<'a>>>,
+        visits: &mut HashMap<&'a str, Visit>,
+    ) -> Result<()> {
+        // Point errors at where the workflow is defined, not where it's named
+        let (&name, rules) = workflows
+            .get_key_value(name)
+            .expect("targets checked before");
+        visits.insert(name, Visit::InProgress);
+        for rule in rules {
+            let Target::Workflow(next) = rule.target else {
+                continue;
+            };
+            match visits.get(next) {
+                Some(Visit::InProgress) => {
+                    return Err(Error::at(
+                        input,
+                        name,
+                        format!("workflow {name} loops back to {next}"),
+                    ))
+                }
+                Some(Visit::Done) => {}
+                None => visit(input, next, workflows, visits)?,
+            }
+        }
+        visits.insert(name, Visit::Done);
+        Ok(())
+    }
+
+    let mut visits = HashMap::new();
+    for &name in names {
+        if !visits.contains_key(name) {
+            visit(input, name, workflows, &mut visits)?;
+        }
+    }
+    Ok(())
+}
+
+impl System<'_> {
+    /// Runs a part through the workflows, starting at `in`.
+    pub fn accepts(&self, part: &Ratings) -> bool {
+        let mut workflow = "in";
+        loop {
+            let rule = self.workflows[workflow]
+                .iter()
+                .find(|rule| rule.accepts.contains(part[rule.category]))
+                .expect("the last rule matches everything");
+            match rule.target {
+                Target::Accept => return true,
+                Target::Reject => return false,
+                Target::Workflow(next) => workflow = next,
+            }
+        }
+    }
+
+    /// How many combinations of ratings within `ranges` are accepted. Each
+    /// rule splits the ranges it sees into the part it sends on and the
+    /// rest, which carries on to the next rule.
+    /// `None` if the count doesn't fit a `u128`.
+    pub fn count_accepted(&self, ranges: RatingRanges) -> Option<u128> {
+        let mut pending = vec![(Target::Workflow("in"), ranges)];
+        let mut accepted = 0;
+        while let Some((target, ranges)) = pending.pop() {
+            let workflow = match target {
+                Target::Accept => {
+                    let combinations = ranges
+                        .iter()
+                        .try_fold(1u128, |product, range| product.checked_mul(range.count()))?;
+                    accepted = combinations.checked_add(accepted)?;
+                    continue;
+                }
+                Target::Reject => continue,
+                Target::Workflow(workflow) => workflow,
+            };
+            let mut remaining = vec![ranges];
+            for rule in &self.workflows[workflow] {
+                let mut rest = Vec::new();
+                for ranges in remaining {
+                    let split = ranges[rule.category].split(&rule.accepts);
+                    let with = |interval| {
+                        let mut ranges = ranges;
+                        ranges[rule.category] = interval;
+                        ranges
+                    };
+                    if let Some(inside) = split.inside {
+                        pending.push((rule.target, with(inside)));
+                    }
+                    rest.extend(split.outside().map(with));
+                }
+                remaining = rest;
+            }
+        }
+        Some(accepted)
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Parsed<'a> = System<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_system(input)
+    }
+    fn part_one(system: &Self::Parsed<'_>) -> Result<Answer> {
+        let total = system
+            .parts
+            .iter()
+            .filter(|part| system.accepts(part))
+            .flatten()
+            .try_fold(0i64, |total, &rating| total.checked_add(rating))
+            .ok_or_else(|| Error::NoSolution("the ratings add up to too much".to_owned()))?;
+        Ok(total.into())
+    }
+    fn part_two(system: &Self::Parsed<'_>) -> Result<Answer> {
+        let ratings = Interval::new(1, 4000);
+        system
+            .count_accepted([ratings; 4])
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("too many combinations to count".to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    static INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+    #[test]
+    fn test_part_one() {
+        let result = Day19::solve(INPUT, Part::One);
+        assert_eq!(result, Ok(19114i64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        let result = Day19::solve(INPUT, Part::Two);
+        assert_eq!(result, Ok(167409079868000u128.into()))
+    }
+    #[test]
+    fn test_unknown_workflow() {
+        let result = Day19::solve("in{x<5:nope,A}\n\n{x=1,m=1,a=1,s=1}", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 1,
+                column: 8,
+                message: "unknown workflow nope".to_owned()
+            })
+        )
+    }
+    #[test]
+    fn test_workflow_loop() {
+        let result = Day19::solve("in{a}\na{in}\n\n{x=1,m=1,a=1,s=1}", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                message: "workflow a loops back to in".to_owned()
+            })
+        )
+    }
+    #[test]
+    fn test_impossible_condition() {
+        let result = Day19::solve(
+            "in{x<-9223372036854775808:A,R}\n\n{x=1,m=1,a=1,s=1}",
+            Part::One,
+        );
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 1,
+                column: 5,
+                message: "condition can never hold".to_owned()
+            })
+        )
+    }
+    #[test]
+    fn test_missing_catch_all() {
+        let result = Day19::solve("in{x<5:A}\n\n{x=10,m=1,a=1,s=1}", Part::One);
+        assert_eq!(
+            result,
+            Err(Error::Parse {
+                line: 1,
+                column: 1,
+                message: "expected a bare target as the last rule".to_owned()
+            })
+        )
+    }
+    #[test]
+    fn test_missing_rating() {
+        let result = Day19::solve("in{A}\n\n{x=1,m=1,a=1,s=1}\n{x=1,m=1,a=1}", Part::One);
+        assert!(matches!(
+            result,
+            Err(Error::Parse {
+                line: 4,
+                column: 1,
+                ..
+            })
+        ))
+    }
+}
@@ -0,0 +1,314 @@
+//! Day 20: Pulse Propagation
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+
+use crate::math::lcm_all;
+use crate::{Answer, Error, Result, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Broadcaster,
+    /// `%`: flips on a low pulse, sending high when it turns on.
+    FlipFlop,
+    /// `&`: sends low once the last pulse from every input was high.
+    Conjunction,
+    /// Only ever receives, like `rx`.
+    Sink,
+}
+
+pub struct Module<'a> {
+    pub name: &'a str,
+    pub kind: Kind,
+    pub inputs: Vec<usize>,
+    pub outputs: Vec<usize>,
+}
+
+/// The modules and how they're wired, numbered in the order they're named.
+pub struct Network<'a> {
+    pub modules: Vec<Module<'a>>,
+    index: HashMap<&'a str, usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub high: bool,
+}
+
+impl<'a> Network<'a> {
+    pub fn parse(input: &'a str) -> Result<Self> {
+        let mut network = Network {
+            modules: Vec::new(),
+            index: HashMap::new(),
+        };
+        let mut wiring = Vec::new();
+        for line in input.lines() {
+            let (module, outputs) = line
+                .split_once(" -> ")
+                .ok_or_else(|| Error::at(input, line, "expected 'module -> outputs'"))?;
+            let (kind, name) = if let Some(name) = module.strip_prefix('%') {
+                (Kind::FlipFlop, name)
+            } else if let Some(name) = module.strip_prefix('&') {
+                (Kind::Conjunction, name)
+            } else if module == "broadcaster" {
+                (Kind::Broadcaster, module)
+            } else {
+                return Err(Error::at(
+                    input,
+                    module,
+                    "expected '%name', '&name' or 'broadcaster'",
+                ));
+            };
+            let id = network.add(name);
+            if network.modules[id].kind != Kind::Sink {
+                return Err(Error::at(input, name, format!("{name} is defined twice")));
+            }
+            network.modules[id].kind = kind;
+            wiring.push((id, outputs));
+        }
+        for (id, outputs) in wiring {
+            for output in outputs.split(", ") {
+                let to = network.add(output);
+                network.modules[id].outputs.push(to);
+                network.modules[to].inputs.push(id);
+            }
+        }
+        Ok(network)
+    }
+
+    /// The module called `name`, added as a sink if it's new.
+    fn add(&mut self, name: &'a str) -> usize {
+        *self.index.entry(name).or_insert_with(|| {
+            self.modules.push(Module {
+                name,
+                kind: Kind::Sink,
+                inputs: Vec::new(),
+                outputs: Vec::new(),
+            });
+            self.modules.len() - 1
+        })
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    /// The network as a Graphviz graph, for a look at its structure.
+    pub fn to_dot(&self) -> String {
+        // Quoted, so names like `node` or `a-b` are still valid IDs
+        let id = |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut dot = String::from("digraph network {\n");
+        for module in &self.modules {
+            let shape = match module.kind {
+                Kind::Broadcaster => "doublecircle",
+                Kind::FlipFlop => "box",
+                Kind::Conjunction => "diamond",
+                Kind::Sink => "circle",
+            };
+            writeln!(dot, "    {} [shape={shape}];", id(module.name)).expect("writing to a String");
+        }
+        for module in &self.modules {
+            for &output in &module.outputs {
+                writeln!(
+                    dot,
+                    "    {} -> {};",
+                    id(module.name),
+                    id(self.modules[output].name)
+                )
+                .expect("writing to a String");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The state of every module while pulses run through a network.
+pub struct Machine<'n> {
+    network: &'n Network<'n>,
+    on: Vec<bool>,
+    /// For each conjunction, the last pulse from each of its inputs, in the
+    /// order of `Module::inputs`.
+    memory: Vec<Vec<bool>>,
+    broadcaster: Option<usize>,
+}
+
+impl<'n> Machine<'n> {
+    pub fn new(network: &'n Network<'n>) -> Self {
+        Machine {
+            network,
+            on: vec![false; network.modules.len()],
+            memory: network
+                .modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+            broadcaster: network.find("broadcaster"),
+        }
+    }
+
+    /// Pushes the button once, sending a low pulse to the broadcaster, and
+    /// calls `on_pulse` with every pulse in the order it's delivered.
+    pub fn press(&mut self, mut on_pulse: impl FnMut(Pulse)) {
+        let Some(broadcaster) = self.broadcaster else {
+            return;
+        };
+        // The button isn't a module; it shows up as coming from the broadcaster
+        let mut queue = VecDeque::from([Pulse {
+            from: broadcaster,
+            to: broadcaster,
+            high: false,
+        }]);
+        while let Some(pulse) = queue.pop_front() {
+            on_pulse(pulse);
+            let module = &self.network.modules[pulse.to];
+            let send = match module.kind {
+                Kind::Broadcaster => Some(pulse.high),
+                Kind::FlipFlop if !pulse.high => {
+                    self.on[pulse.to] = !self.on[pulse.to];
+                    Some(self.on[pulse.to])
+                }
+                Kind::FlipFlop => None,
+                Kind::Conjunction => {
+                    let memory = &mut self.memory[pulse.to];
+                    for (input, last) in module.inputs.iter().zip(memory.iter_mut()) {
+                        if *input == pulse.from {
+                            *last = pulse.high;
+                        }
+                    }
+                    Some(!memory.iter().all(|&high| high))
+                }
+                Kind::Sink => None,
+            };
+            if let Some(high) = send {
+                queue.extend(module.outputs.iter().map(|&to| Pulse {
+                    from: pulse.to,
+                    to,
+                    high,
+                }));
+            }
+        }
+    }
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Parsed<'a> = Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Network::parse(input)
+    }
+    fn part_one(network: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut machine = Machine::new(network);
+        let (mut low, mut high) = (0u64, 0u64);
+        for _ in 0..1000 {
+            machine.press(|pulse| if pulse.high { high += 1 } else { low += 1 });
+        }
+        Ok((low * high).into())
+    }
+    fn part_two(network: &Self::Parsed<'_>) -> Result<Answer> {
+        // rx is fed by a single conjunction, which only sends it a low pulse
+        // once all its inputs have just sent high. Each input does so on its
+        // own cycle, so watch for the first press each of them sends high.
+        let no_solution = || Error::NoSolution("rx isn't fed by a single conjunction".to_owned());
+        let rx = network.find("rx").ok_or_else(no_solution)?;
+        let &[feeder] = &network.modules[rx].inputs[..] else {
+            return Err(no_solution());
+        };
+        if network.modules[feeder].kind != Kind::Conjunction {
+            return Err(no_solution());
+        }
+        let watched = &network.modules[feeder].inputs;
+        let mut cycles: Vec<Option<u64>> = vec![None; watched.len()];
+        let mut machine = Machine::new(network);
+        for press in 1..=1_000_000 {
+            machine.press(|pulse| {
+                if pulse.to == feeder && pulse.high {
+                    let index = watched.iter().position(|&input| input == pulse.from);
+                    if let Some(cycle) = index.map(|index| &mut cycles[index]) {
+                        cycle.get_or_insert(press);
+                    }
+                }
+            });
+            if let Some(cycles) = cycles.iter().copied().collect::<Option<Vec<_>>>() {
+                let presses = lcm_all(cycles).ok_or_else(|| {
+                    Error::NoSolution("rx takes too many presses to count".to_owned())
+                })?;
+                return Ok(presses.into());
+            }
+        }
+        Err(Error::NoSolution(
+            "the inputs to rx didn't all fire within a million presses".to_owned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_part_one() {
+        let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+        let result = Day20::solve(input, Part::One);
+        assert_eq!(result, Ok(32000000u64.into()))
+    }
+    #[test]
+    fn test_part_one_with_output() {
+        let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+        let result = Day20::solve(input, Part::One);
+        assert_eq!(result, Ok(11687500u64.into()))
+    }
+    #[test]
+    fn test_part_two() {
+        // Inverted flip-flops that send high every second and every fourth press
+        let input = "broadcaster -> a
+%a -> b, na
+%b -> nb
+&na -> all
+&nb -> all
+&all -> rx";
+        let network = Network::parse(input).unwrap();
+        let rx = network.find("rx").unwrap();
+        let mut machine = Machine::new(&network);
+        let mut presses = 0u64;
+        let mut done = false;
+        while !done {
+            presses += 1;
+            machine.press(|pulse| done |= pulse.to == rx && !pulse.high);
+        }
+        assert_eq!(Day20::part_two(&network), Ok(presses.into()));
+        assert!(presses > 1);
+    }
+    #[test]
+    fn test_to_dot() {
+        let network =
+            Network::parse("broadcaster -> a\n%a -> node\n&node -> a, say\"hi\"").unwrap();
+        assert_eq!(
+            network.to_dot(),
+            r#"digraph network {
+    "broadcaster" [shape=doublecircle];
+    "a" [shape=box];
+    "node" [shape=diamond];
+    "say\"hi\"" [shape=circle];
+    "broadcaster" -> "a";
+    "a" -> "node";
+    "node" -> "a";
+    "node" -> "say\"hi\"";
+}
+"#
+        );
+    }
+}
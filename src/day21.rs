@@ -0,0 +1,187 @@
+//! Day 21: Step Counter
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::grid::{Direction, Grid, Pos};
+use crate::math::quadratic_at;
+use crate::{Answer, Error, Result, Solver};
+
+/// The map of rocks (`true`), and where the elf starts.
+pub struct Garden {
+    pub rocks: Grid<bool>,
+    pub start: Pos,
+}
+
+impl Garden {
+    pub fn parse(input: &str) -> Result<Self> {
+        let rocks = Grid::parse(input, |label| match label {
+            '#' => Some(true),
+            '.' | 'S' => Some(false),
+            _ => None,
+        })?;
+        let start = input
+            .lines()
+            .enumerate()
+            .find_map(|(row, line)| Some(Pos::new(row, line.chars().position(|c| c == 'S')?)))
+            .ok_or_else(|| Error::at(input, input, "no starting position 'S'"))?;
+        Ok(Garden { rocks, start })
+    }
+
+    /// Whether `(row, col)` is a rock, with the map repeated in every
+    /// direction if `infinite`, and only rocks outside it otherwise.
+    fn blocked(&self, (row, col): (i64, i64), infinite: bool) -> bool {
+        let (height, width) = (self.rocks.height() as i64, self.rocks.width() as i64);
+        let inside = (0..height).contains(&row) && (0..width).contains(&col);
+        if !infinite && !inside {
+            return true;
+        }
+        let pos = Pos::new(
+            row.rem_euclid(height) as usize,
+            col.rem_euclid(width) as usize,
+        );
+        self.rocks[pos]
+    }
+
+    /// For each of `steps`, how many plots can be reached in exactly that
+    /// many steps. A plot reached in `d` steps can be reached again in any
+    /// `d + 2n` by stepping back and forth, so it counts when `d` is no more
+    /// and has the same parity.
+    pub fn reachable(&self, steps: &[u64], infinite: bool) -> Vec<u64> {
+        let max = steps.iter().copied().max().unwrap_or(0);
+        let start = (self.start.row as i64, self.start.col as i64);
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0u64)]);
+        let mut counts = vec![0; steps.len()];
+        while let Some((pos, distance)) = queue.pop_front() {
+            for (count, &target) in counts.iter_mut().zip(steps) {
+                if distance <= target && distance % 2 == target % 2 {
+                    *count += 1;
+                }
+            }
+            if distance == max {
+                continue;
+            }
+            for direction in Direction::ALL {
+                let (rows, cols) = direction.offset();
+                let next = (pos.0 + rows as i64, pos.1 + cols as i64);
+                if !self.blocked(next, infinite) && seen.insert(next) {
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        counts
+    }
+
+    /// Plots reachable in exactly `steps` on the infinitely repeated map,
+    /// for step counts too large to walk. Puzzle inputs are square, with the
+    /// start in the middle and its row and column clear, so the frontier
+    /// grows by one map per `width` steps and the count at `rest + n * width`
+    /// is quadratic in `n`; it's fitted through the first three. Gardens
+    /// without that shape have no solution here.
+    pub fn reachable_far(&self, steps: u64) -> Result<u64> {
+        let width = self.rocks.width() as u64;
+        if width == 0 || width != self.rocks.height() as u64 {
+            return Err(Error::NoSolution("the garden isn't square".to_owned()));
+        }
+        let middle = self.rocks.width() / 2;
+        if self.start != Pos::new(middle, middle) {
+            return Err(Error::NoSolution(
+                "the start isn't in the middle of the garden".to_owned(),
+            ));
+        }
+        let clear = self.rocks.positions().all(|pos| {
+            !self.rocks[pos] || (pos.row != self.start.row && pos.col != self.start.col)
+        });
+        if !clear {
+            return Err(Error::NoSolution(
+                "the start's row and column aren't clear of rocks".to_owned(),
+            ));
+        }
+        let rest = steps % width;
+        let samples = [rest, rest + width, rest + 2 * width];
+        if steps <= samples[2] {
+            return Ok(self.reachable(&[steps], true)[0]);
+        }
+        let counts = self.reachable(&samples, true);
+        let too_many = || Error::NoSolution("too many plots to count".to_owned());
+        let counts: Vec<i64> = counts
+            .into_iter()
+            .map(i64::try_from)
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| too_many())?;
+        let maps = i64::try_from(steps / width).map_err(|_| too_many())?;
+        let plots = quadratic_at([counts[0], counts[1], counts[2]], maps).ok_or_else(too_many)?;
+        u64::try_from(plots).map_err(|_| too_many())
+    }
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Parsed<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Garden::parse(input)
+    }
+    fn part_one(garden: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(garden.reachable(&[64], false)[0].into())
+    }
+    fn part_two(garden: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(garden.reachable_far(26_501_365)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+    #[test]
+    fn test_part_one() {
+        let garden = Garden::parse(INPUT).unwrap();
+        assert_eq!(garden.reachable(&[6], false), [16]);
+    }
+    #[test]
+    fn test_infinite() {
+        let garden = Garden::parse(INPUT).unwrap();
+        let steps = [6, 10, 50, 100, 500];
+        assert_eq!(garden.reachable(&steps, true), [16, 50, 1594, 6536, 167004]);
+    }
+    #[test]
+    fn test_reachable_far() {
+        // The example's start row and column aren't clear, so fit on a
+        // garden where they are, against walking it
+        let input = ".....
+.#.#.
+..S..
+.#...
+.....";
+        let garden = Garden::parse(input).unwrap();
+        for steps in [12, 27, 53] {
+            assert_eq!(
+                garden.reachable_far(steps),
+                Ok(garden.reachable(&[steps], true)[0])
+            );
+        }
+    }
+    #[test]
+    fn test_reachable_far_needs_clear_lines() {
+        let garden = Garden::parse(INPUT).unwrap();
+        assert_eq!(
+            garden.reachable_far(26_501_365),
+            Err(Error::NoSolution(
+                "the start's row and column aren't clear of rocks".to_owned()
+            ))
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+/// Everything that can go wrong while reading input or solving a problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input doesn't have the expected shape; `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parsed, but has no answer.
+    NoSolution(String),
+    /// The input couldn't be read.
+    Io(String),
+    /// A `--param` the day doesn't take, or whose value doesn't parse.
+    Param(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// A parse error pointing at `part`, which should be a slice of `input`.
+    /// Anything else is reported at the start of the input.
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Error::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, column {column}: {message}"),
+            Error::NoSolution(message) => write!(f, "no solution: {message}"),
+            Error::Io(message) => write!(f, "{message}"),
+            Error::Param(message) => write!(f, "bad parameter: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
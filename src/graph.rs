@@ -0,0 +1,141 @@
+//! A directed graph where every node has a left and a right successor.
+
+use std::collections::HashMap;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    pub fn from_char(label: char) -> Option<Self> {
+        match label {
+            'L' => Some(Turn::Left),
+            'R' => Some(Turn::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Nodes are numbered in the order they were listed.
+pub struct NodeMap<'a> {
+    names: Vec<&'a str>,
+    index: HashMap<&'a str, usize>,
+    edges: Vec<[usize; 2]>,
+}
+
+/// Where a walk starts looping. Steps are counted from the start of the walk,
+/// and the loop repeats steps `start..start + length` forever.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: u64,
+    pub length: u64,
+    /// Steps, up to the end of the first loop, that landed on an end node.
+    pub ends: Vec<u64>,
+}
+
+impl Cycle {
+    /// The period with which end nodes come up, if they come up exactly at
+    /// every multiple of it, which makes lining up several walks an lcm.
+    pub fn period(&self) -> Option<u64> {
+        let &first = self.ends.first()?;
+        let regular = first >= self.start
+            && self.length.is_multiple_of(first)
+            && self.ends.iter().zip(1..).all(|(&end, n)| end == first * n);
+        regular.then_some(first)
+    }
+}
+
+impl<'a> NodeMap<'a> {
+    /// Parses lines like `AAA = (BBB, CCC)`, with `input` used to place errors.
+    pub fn parse(input: &'a str, lines: &'a str) -> Result<Self> {
+        let mut names = Vec::new();
+        let mut successors = Vec::new();
+        for line in lines.lines().filter(|line| !line.is_empty()) {
+            let (name, rest) = line
+                .split_once(" = ")
+                .ok_or_else(|| Error::at(input, line, "expected 'NODE = (LEFT, RIGHT)'"))?;
+            let pair = rest
+                .strip_prefix('(')
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|rest| rest.split_once(", "))
+                .ok_or_else(|| Error::at(input, rest, "expected '(LEFT, RIGHT)'"))?;
+            names.push(name);
+            successors.push(pair);
+        }
+        let index: HashMap<_, _> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let lookup = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| Error::at(input, name, format!("unknown node {name}")))
+        };
+        let edges = successors
+            .iter()
+            .map(|&(left, right)| Ok([lookup(left)?, lookup(right)?]))
+            .collect::<Result<_>>()?;
+        Ok(NodeMap {
+            names,
+            index,
+            edges,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn name(&self, node: usize) -> &'a str {
+        self.names[node]
+    }
+
+    pub fn next(&self, node: usize, turn: Turn) -> usize {
+        match turn {
+            Turn::Left => self.edges[node][0],
+            Turn::Right => self.edges[node][1],
+        }
+    }
+
+    /// Follows `turns` over and over from `start` until the walk repeats
+    /// itself, noting each step that lands on a node where `is_end` holds.
+    /// `turns` must not be empty.
+    pub fn find_cycle(
+        &self,
+        start: usize,
+        turns: &[Turn],
+        is_end: impl Fn(usize) -> bool,
+    ) -> Cycle {
+        // Step at which each (node, turn index) state was first seen
+        let mut seen = vec![None; self.len() * turns.len()];
+        let mut ends = Vec::new();
+        let mut node = start;
+        for step in 0u64.. {
+            let turn = step as usize % turns.len();
+            let state = node * turns.len() + turn;
+            if let Some(first) = seen[state] {
+                return Cycle {
+                    start: first,
+                    length: step - first,
+                    ends,
+                };
+            }
+            seen[state] = Some(step);
+            if step > 0 && is_end(node) {
+                ends.push(step);
+            }
+            node = self.next(node, turns[turn]);
+        }
+        unreachable!("there are only so many states")
+    }
+}
@@ -0,0 +1,210 @@
+//! A rectangular grid of cells, as most puzzle maps come.
+
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+/// A cell's coordinates, counted from the top left.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    /// Row and column change of one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Reads one cell per character, one row per line. `cell` returns `None`
+    /// for characters that don't belong, which are reported as parse errors.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Self::parse_block(input, input, cell)
+    }
+
+    /// Like `parse`, for a `block` that is a slice of a larger `input`, so
+    /// errors point into the whole input.
+    pub fn parse_block(
+        input: &str,
+        block: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in block.lines() {
+            let start = cells.len();
+            for (i, label) in line.char_indices() {
+                cells.push(cell(label).ok_or_else(|| {
+                    Error::at(input, &line[i..], format!("unexpected {label:?}"))
+                })?);
+            }
+            let row_width = cells.len() - start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(Error::at(input, line, "rows differ in length"));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.height).flat_map(|row| (0..self.width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn position(&self, mut matches: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.positions().find(|&pos| matches(&self[pos]))
+    }
+
+    /// One step from `pos`, unless that leaves the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        let (rows, cols) = direction.offset();
+        let next = Pos::new(
+            pos.row.checked_add_signed(rows)?,
+            pos.col.checked_add_signed(cols)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to four cells sharing an edge with `pos`.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Direction, Pos)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.step(pos, direction)?)))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `pos`.
+    pub fn adjacent(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let rows = pos.row.saturating_sub(1)..=pos.row + 1;
+        rows.flat_map(move |row| {
+            (pos.col.saturating_sub(1)..=pos.col + 1).map(move |col| Pos::new(row, col))
+        })
+        .filter(move |&other| other != pos && self.contains(other))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{pos:?} is outside the grid");
+        &self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{pos:?} is outside the grid");
+        &mut self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+/// The part of `input` starting at the cell `pos` was parsed from, for
+/// pointing errors at it.
+pub fn source(input: &str, pos: Pos) -> &str {
+    let Some(line) = input.lines().nth(pos.row) else {
+        return input;
+    };
+    let offset = line
+        .char_indices()
+        .nth(pos.col)
+        .map_or(line.len(), |(i, _)| i);
+    &line[offset..]
+}
@@ -0,0 +1,26 @@
+//! Helpers for splitting up puzzle input.
+
+/// The blocks of `input` separated by blank lines, as slices of it so errors
+/// can still be placed. Runs of blank lines separate just once, and blocks
+/// don't include their final line break.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
@@ -0,0 +1,89 @@
+//! Inclusive integer intervals, and splitting them against each other.
+
+/// The integers `start..=last`; never empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub last: i64,
+}
+
+/// An interval cut by another: the part inside it, and the parts before and
+/// after it. Whichever parts would be empty are `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Split {
+    pub before: Option<Interval>,
+    pub inside: Option<Interval>,
+    pub after: Option<Interval>,
+}
+
+impl Split {
+    /// The parts outside the cutting interval.
+    pub fn outside(&self) -> impl Iterator<Item = Interval> {
+        self.before.into_iter().chain(self.after)
+    }
+}
+
+impl Interval {
+    pub fn new(start: i64, last: i64) -> Self {
+        assert!(start <= last);
+        Interval { start, last }
+    }
+
+    /// `start..=last`, or `None` if that's empty.
+    pub fn checked(start: i64, last: i64) -> Option<Self> {
+        (start <= last).then_some(Interval { start, last })
+    }
+
+    /// How many integers it holds; a `u128` since all of `i64` is one more
+    /// than `u64` can count.
+    pub fn count(&self) -> u128 {
+        u128::from(self.start.abs_diff(self.last)) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        (self.start..=self.last).contains(&value)
+    }
+
+    pub fn shift(self, offset: i64) -> Self {
+        Interval::new(self.start + offset, self.last + offset)
+    }
+
+    /// Cuts this interval where `other` begins and ends.
+    pub fn split(&self, other: &Interval) -> Split {
+        // `other.start - 1` and `other.last + 1` only when they're in range
+        let before = (other.start > self.start)
+            .then(|| Interval::checked(self.start, self.last.min(other.start - 1)))
+            .flatten();
+        let after = (other.last < self.last)
+            .then(|| Interval::checked(self.start.max(other.last + 1), self.last))
+            .flatten();
+        Split {
+            before,
+            inside: Interval::checked(self.start.max(other.start), self.last.min(other.last)),
+            after,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_at_the_ends() {
+        let split = Interval::new(i64::MIN, 5).split(&Interval::new(i64::MIN, 3));
+        assert_eq!(split.before, None);
+        assert_eq!(split.inside, Some(Interval::new(i64::MIN, 3)));
+        assert_eq!(split.after, Some(Interval::new(4, 5)));
+
+        let split = Interval::new(-5, i64::MAX).split(&Interval::new(0, i64::MAX));
+        assert_eq!(split.before, Some(Interval::new(-5, -1)));
+        assert_eq!(split.inside, Some(Interval::new(0, i64::MAX)));
+        assert_eq!(split.after, None);
+    }
+    #[test]
+    fn test_count_everything() {
+        let everything = Interval::new(i64::MIN, i64::MAX);
+        assert_eq!(everything.count(), 1 << 64);
+    }
+}
@@ -0,0 +1,58 @@
+//! Number theory shared between days.
+
+/// Greatest common divisor; `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple; 0 if either is 0, `None` on overflow.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of every number, or 1 for none; `None` on overflow.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+/// The value after `values`, taking them as samples of a polynomial at
+/// evenly spaced points: take differences until they're all zero, then add
+/// the last of each row back up. `None` for no values or on overflow.
+pub fn extrapolate_next(values: &[i64]) -> Option<i64> {
+    let mut row = values.to_vec();
+    let mut next = 0i64;
+    while row.iter().any(|&value| value != 0) {
+        next = next.checked_add(*row.last()?)?;
+        row = row
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<_>>()?;
+    }
+    (!values.is_empty()).then_some(next)
+}
+
+/// The value before `values`; the same fit run backwards.
+pub fn extrapolate_previous(values: &[i64]) -> Option<i64> {
+    let reversed: Vec<_> = values.iter().rev().copied().collect();
+    extrapolate_next(&reversed)
+}
+
+/// The quadratic through `(0, samples[0])`, `(1, samples[1])` and
+/// `(2, samples[2])`, evaluated at `x`, in Newton's form. `None` on overflow.
+pub fn quadratic_at(samples: [i64; 3], x: i64) -> Option<i64> {
+    let first = samples[1].checked_sub(samples[0])?;
+    let second = samples[2]
+        .checked_sub(samples[1].checked_mul(2)?)?
+        .checked_add(samples[0])?;
+    // x * (x - 1) is always even
+    let pairs = x.checked_mul(x.checked_sub(1)?)? / 2;
+    samples[0]
+        .checked_add(first.checked_mul(x)?)?
+        .checked_add(second.checked_mul(pairs)?)
+}
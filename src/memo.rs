@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cache for recursive functions. The function gets the memo back so it
+/// can recurse through it:
+///
+/// ```
+/// use aoc23::memo::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_insert_with(n, |memo| match n {
+///         0 | 1 => n,
+///         _ => fib(memo, n - 1) + fib(memo, n - 2),
+///     })
+/// }
+/// assert_eq!(fib(&mut Memo::new(), 90), 2880067194370816120);
+/// ```
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The cached value for `key`, computing it with `f` the first time.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,71 @@
+//! Polygons with vertices on the integer lattice.
+
+use crate::grid::Direction;
+use crate::math::gcd;
+
+/// A closed polygon, as its vertices in order, `(row, col)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    pub vertices: Vec<(i64, i64)>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<(i64, i64)>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// The polygon traced by walking each step from the origin, or `None`
+    /// if the walk doesn't end back at the origin or runs off the `i64`s.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, u64)>) -> Option<Self> {
+        let mut vertices = Vec::new();
+        let (mut row, mut col) = (0i64, 0i64);
+        for (direction, length) in steps {
+            vertices.push((row, col));
+            let (rows, cols) = direction.offset();
+            let length = i64::try_from(length).ok()?;
+            row = row.checked_add(length.checked_mul(rows as i64)?)?;
+            col = col.checked_add(length.checked_mul(cols as i64)?)?;
+        }
+        (row == 0 && col == 0).then_some(Polygon { vertices })
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the enclosed area, by the shoelace formula; doubled to stay
+    /// whole. `None` on overflow.
+    pub fn twice_area(&self) -> Option<u64> {
+        self.edges()
+            .try_fold(0i64, |sum, ((row_a, col_a), (row_b, col_b))| {
+                let cross = col_a
+                    .checked_mul(row_b)?
+                    .checked_sub(col_b.checked_mul(row_a)?)?;
+                sum.checked_add(cross)
+            })
+            .map(i64::unsigned_abs)
+    }
+
+    /// Lattice points on the edges, or `None` on overflow.
+    pub fn boundary_points(&self) -> Option<u64> {
+        self.edges()
+            .try_fold(0u64, |sum, ((row_a, col_a), (row_b, col_b))| {
+                sum.checked_add(gcd(row_a.abs_diff(row_b), col_a.abs_diff(col_b)))
+            })
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem: `A = i + b/2 - 1`.
+    /// `None` on overflow, or if the edges fold back over each other so the
+    /// theorem doesn't hold.
+    pub fn interior_points(&self) -> Option<u64> {
+        let twice_area = self.twice_area()?.checked_add(2)?;
+        Some(twice_area.checked_sub(self.boundary_points()?)? / 2)
+    }
+
+    /// Lattice points inside or on the edges, or `None` as for
+    /// [`Polygon::interior_points`].
+    pub fn lattice_points(&self) -> Option<u64> {
+        self.interior_points()?.checked_add(self.boundary_points()?)
+    }
+}
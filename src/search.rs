@@ -0,0 +1,60 @@
+//! Shortest paths over any state space.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Dijkstra's algorithm: the lowest total cost from any of `starts` to a
+/// state where `is_goal` holds, along with that state. `neighbours` gives
+/// the states one move away from a state and what each move costs.
+pub fn dijkstra<S, N>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> N,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(u64, S)>
+where
+    S: Hash + Eq + Clone,
+    N: IntoIterator<Item = (S, u64)>,
+{
+    // States live in `states`; the queue refers to them by index so that
+    // they needn't be ordered themselves
+    let mut states = Vec::new();
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            entry.insert(0);
+            queue.push(Reverse((0, states.len())));
+            states.push(start);
+        }
+    }
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        let state = states[index].clone();
+        if best.get(&state).is_some_and(|&known| known < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((cost, state));
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            let improved = match best.entry(next.clone()) {
+                Entry::Occupied(mut entry) if next_cost < *entry.get() => {
+                    entry.insert(next_cost);
+                    true
+                }
+                Entry::Occupied(_) => false,
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                    true
+                }
+            };
+            if improved {
+                queue.push(Reverse((next_cost, states.len())));
+                states.push(next);
+            }
+        }
+    }
+    None
+}
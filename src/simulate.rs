@@ -0,0 +1,25 @@
+//! Helpers for running simulations far into the future.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The state after `steps` applications of `step` to `start`. Once a state
+/// repeats, the rest of the run is a loop, so the answer is read off it
+/// without stepping through the remaining (possibly billions of) steps.
+pub fn nth_state<S: Hash + Eq + Clone>(start: S, steps: u64, mut step: impl FnMut(&S) -> S) -> S {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = start;
+    for index in 0..steps {
+        if let Some(&first) = seen.get(&state) {
+            let length = index - first;
+            let offset = (steps - first) % length;
+            return history.swap_remove((first + offset) as usize);
+        }
+        seen.insert(state.clone(), index);
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
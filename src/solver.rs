@@ -0,0 +1,129 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::{Answer, Error, Params, Result};
+
+/// One of the two parts every puzzle has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day's puzzle: parse the input once, then answer either part from it.
+pub trait Solver {
+    type Parsed<'a>;
+
+    /// Names of the parameters `parse_with` reads.
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    /// Parses with parameters from the command line. Days without any keep
+    /// this default.
+    fn parse_with<'a>(input: &'a str, _params: &Params) -> Result<Self::Parsed<'a>> {
+        Self::parse(input)
+    }
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    /// Parses `input` and answers one part, for callers that don't need timings.
+    fn solve(input: &str, part: Part) -> Result<Answer> {
+        Self::solve_with(input, &Params::default(), part)
+    }
+
+    fn solve_with(input: &str, params: &Params, part: Part) -> Result<Answer> {
+        let parsed = Self::parse_with(input, params)?;
+        match part {
+            Part::One => Self::part_one(&parsed),
+            Part::Two => Self::part_two(&parsed),
+        }
+    }
+}
+
+/// Registry entry pairing a day number with its type-erased solver.
+pub struct Day {
+    pub number: u8,
+    pub params: &'static [&'static str],
+    pub solve: fn(&str, &Params, &[Part]) -> Result<Solved>,
+}
+
+impl Day {
+    pub const fn new<S: Solver>(number: u8) -> Self {
+        Self {
+            number,
+            params: S::PARAMS,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Rejects parameters this day doesn't read, so typos don't go unnoticed.
+    pub fn check_params(&self, params: &Params) -> Result<()> {
+        match params.keys().find(|key| !self.params.contains(key)) {
+            Some(key) => Err(Error::Param(format!(
+                "day {} has no parameter {key} (available: {})",
+                self.number,
+                if self.params.is_empty() {
+                    "none".to_owned()
+                } else {
+                    self.params.join(", ")
+                }
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Answers to the requested parts of one input, with the wall time spent
+/// parsing it once and solving each part.
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<Timed>,
+}
+
+/// An answer together with the wall time spent solving for it.
+pub struct Timed {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve: Duration,
+}
+
+fn solve<S: Solver>(input: &str, params: &Params, parts: &[Part]) -> Result<Solved> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, params)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed)?,
+                Part::Two => S::part_two(&parsed)?,
+            };
+            Ok(Timed {
+                part,
+                answer,
+                solve: start.elapsed(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Solved { parse, parts })
+}